@@ -0,0 +1,126 @@
+//! Measures the cost of building and combining complex numbers in a tight loop, for the current
+//! Cartesian-only `ComplexNumber` and for the layout of earlier versions, which cached the polar form.
+//!
+//! Run with `cargo bench --bench storage`. Each case is timed several times and the fastest run is
+//! reported, in nanoseconds per element.
+
+use complexible::complex_numbers::*;
+use std::f64::consts::PI;
+use std::hint::black_box;
+use std::time::Instant;
+
+const N: usize = 1 << 16;
+const RUNS: usize = 20;
+
+/// The layout of earlier versions: the Cartesian parts together with the magnitude and the angle in
+/// both degrees and radians, all computed on construction. None of the cases reads `degrees` back.
+#[derive(Clone, Copy)]
+#[allow(dead_code)]
+struct CachedPolar {
+    real: f64,
+    imaginary: f64,
+    magnitude: f64,
+    degrees: f64,
+    radians: f64,
+}
+impl CachedPolar {
+    fn from_cartesian(real: f64, imaginary: f64) -> CachedPolar {
+        let magnitude = (real.powi(2) + imaginary.powi(2)).sqrt();
+        let radians = imaginary.atan2(real);
+        let degrees = radians * (180.0 / PI);
+        CachedPolar { real, imaginary, magnitude, degrees, radians }
+    }
+
+    fn from_polar(magnitude: f64, radians: f64) -> CachedPolar {
+        let degrees = radians * (180.0 / PI);
+        let real = magnitude * radians.cos();
+        let imaginary = magnitude * radians.sin();
+        CachedPolar { real, imaginary, magnitude, degrees, radians }
+    }
+
+    fn add(&self, z2: &CachedPolar) -> CachedPolar {
+        CachedPolar::from_cartesian(self.real + z2.real, self.imaginary + z2.imaginary)
+    }
+
+    /// Multiplies in polar form, rounding the angles to five decimals as the old `angle_in_rads` did.
+    fn mul(&self, z2: &CachedPolar) -> CachedPolar {
+        let round = |n: f64| (n * 1e5).round() / 1e5;
+        CachedPolar::from_polar(self.magnitude * z2.magnitude, round(self.radians) + round(z2.radians))
+    }
+}
+
+fn time(name: &str, mut case: impl FnMut() -> f64) {
+    let mut best = f64::INFINITY;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        black_box(case());
+        best = best.min(start.elapsed().as_secs_f64());
+    }
+    println!("{:<40} {:>8.2} ns/element", name, best * 1e9 / N as f64);
+}
+
+fn main() {
+    let parts: Vec<(f64, f64)> = (0..N)
+        .map(|k| ((k as f64).sin(), (k as f64).cos()))
+        .collect();
+    let signal: Vec<ComplexNumber> = parts
+        .iter()
+        .map(|&(re, im)| ComplexNumber::from_cartesian(re, im))
+        .collect();
+    let taps: Vec<ComplexNumber> = parts
+        .iter()
+        .rev()
+        .map(|&(re, im)| ComplexNumber::from_cartesian(im, re))
+        .collect();
+    let cached_signal: Vec<CachedPolar> = signal
+        .iter()
+        .map(|z| CachedPolar::from_cartesian(z.real(), z.imag()))
+        .collect();
+    let cached_taps: Vec<CachedPolar> = taps
+        .iter()
+        .map(|z| CachedPolar::from_cartesian(z.real(), z.imag()))
+        .collect();
+
+    time("from_cartesian (cartesian only)", || {
+        parts
+            .iter()
+            .map(|&(re, im)| ComplexNumber::from_cartesian(black_box(re), im).real())
+            .sum()
+    });
+    time("from_cartesian (cached polar form)", || {
+        parts
+            .iter()
+            .map(|&(re, im)| black_box(CachedPolar::from_cartesian(black_box(re), im)).real)
+            .sum()
+    });
+    time("from_polar (cartesian only)", || {
+        parts
+            .iter()
+            .map(|&(m, a)| ComplexNumber::from_polar(black_box(m), Angle::from_radians(a)).real())
+            .sum()
+    });
+    time("from_polar (cached polar form)", || {
+        parts
+            .iter()
+            .map(|&(m, a)| black_box(CachedPolar::from_polar(black_box(m), a)).real)
+            .sum()
+    });
+    time("multiply-accumulate (cartesian only)", || {
+        let mut acc = ComplexNumber::from_real(0.0);
+        for (x, h) in signal.iter().zip(&taps) {
+            acc = acc.add(&x.mul(h));
+        }
+        acc.real()
+    });
+    time("multiply-accumulate (cached polar form)", || {
+        let mut acc = CachedPolar::from_cartesian(0.0, 0.0);
+        for (x, h) in cached_signal.iter().zip(&cached_taps) {
+            acc = acc.add(&x.mul(h));
+        }
+        acc.real
+    });
+    time("abs (cartesian only)", || signal.iter().map(|z| z.abs()).sum());
+    time("abs (cached polar form)", || {
+        cached_signal.iter().map(|z| black_box(z).magnitude).sum()
+    });
+}
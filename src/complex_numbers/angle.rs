@@ -1,15 +1,19 @@
-use std::f64::consts::PI;
+use super::float::Float;
+use super::Angle;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// Represents an angle value in radians.
 ///
 /// # Fields
 ///
 /// * `value` - The value of the angle in radians.
-#[derive(Debug)]
-pub struct Radian {
-    pub value: f64,
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Radian<T = f64> {
+    pub value: T,
 }
-impl Radian {
+impl<T: Float> Radian<T> {
     /// Creates a new `Radian` value from a given angle value in radians.
     ///
     /// # Arguments
@@ -24,7 +28,7 @@ impl Radian {
     /// let radian = Radian::from(std::f64::consts::PI);
     /// assert_eq!(radian.value, std::f64::consts::PI);
     /// ```
-    pub fn from(value: f64) -> Radian {
+    pub fn from(value: T) -> Radian<T> {
         Radian { value }
     } 
     /// Converts the `Radian` value to degrees.
@@ -38,9 +42,37 @@ impl Radian {
     /// let degree = radian.to_degrees();
     /// assert_eq!(degree.value, 180.0);
     /// ```
-    pub fn to_degrees(&self) -> Degree {
+    pub fn to_degrees(&self) -> Degree<T> {
         radianto_degrees(self.value)
     }
+
+    /// Returns the sine of the angle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::angle::* ;
+    ///
+    /// assert_eq!(Radian::from(0.0).sin(), 0.0);
+    /// ```
+    pub fn sin(&self) -> T {
+        self.value.sin()
+    }
+
+    /// Returns the cosine of the angle.
+    pub fn cos(&self) -> T {
+        self.value.cos()
+    }
+
+    /// Returns the tangent of the angle.
+    pub fn tan(&self) -> T {
+        self.value.tan()
+    }
+
+    /// Returns the sine and the cosine of the angle.
+    pub fn sin_cos(&self) -> (T, T) {
+        (self.value.sin(), self.value.cos())
+    }
 }
 
 /// Represents an angle value in degrees.
@@ -48,11 +80,11 @@ impl Radian {
 /// # Fields
 ///
 /// * `value` - The value of the angle in degrees.
-#[derive(Debug)]
-pub struct Degree {
-    pub value: f64,
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Degree<T = f64> {
+    pub value: T,
 }
-impl Degree {
+impl<T: Float> Degree<T> {
      /// Creates a new `Degree` value from a given angle value in degrees.
     ///
     /// # Arguments
@@ -67,7 +99,7 @@ impl Degree {
     /// let degree = Degree::from(180.0);
     /// assert_eq!(degree.value, 180.0);
     /// ```
-    pub fn from(value: f64) -> Degree {
+    pub fn from(value: T) -> Degree<T> {
         Degree { value }
     } 
 
@@ -82,9 +114,115 @@ impl Degree {
     /// let radian = degree.to_radians();
     /// assert_eq!(radian.value, std::f64::consts::PI);
     /// ```
-    pub fn to_radians(&self) -> Radian {
+    pub fn to_radians(&self) -> Radian<T> {
         degreesto_radians(self.value)
     }
+
+    /// Returns the sine of the angle. Multiples of 90° give exact results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::angle::* ;
+    ///
+    /// assert_eq!(Degree::from(180.0).sin(), 0.0);
+    /// assert_eq!(Degree::from(-90.0).sin(), -1.0);
+    /// ```
+    pub fn sin(&self) -> T {
+        sin_cos_degrees(self.value).0
+    }
+
+    /// Returns the cosine of the angle. Multiples of 90° give exact results.
+    pub fn cos(&self) -> T {
+        sin_cos_degrees(self.value).1
+    }
+
+    /// Returns the tangent of the angle. Odd multiples of 90° give an infinite result.
+    pub fn tan(&self) -> T {
+        let (sin, cos) = sin_cos_degrees(self.value);
+        sin / cos
+    }
+
+    /// Returns the sine and the cosine of the angle.
+    pub fn sin_cos(&self) -> (T, T) {
+        sin_cos_degrees(self.value)
+    }
+}
+
+/// The unit an `Angle` is expressed in.
+///
+/// # Variants
+///
+/// * `Radians` - The angle is stored in radians, `2π` to the turn.
+/// * `Degrees` - The angle is stored in degrees, `360` to the turn.
+/// * `Gradians` - The angle is stored in gradians (gons), `400` to the turn.
+/// * `Turns` - The angle is stored in turns (revolutions).
+/// * `Milliradians` - The angle is stored in milliradians, `2000π` to the turn.
+/// * `Arcminutes` - The angle is stored in minutes of arc, `60` to the degree.
+/// * `Arcseconds` - The angle is stored in seconds of arc, `60` to the minute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AngleUnit {
+    Radians,
+    Degrees,
+    Gradians,
+    Turns,
+    Milliradians,
+    Arcminutes,
+    Arcseconds,
+}
+impl AngleUnit {
+    /// Returns the number of units in one turn, as a multiple of `π` if the flag is set.
+    fn per_turn(&self) -> (f64, bool) {
+        match self {
+            AngleUnit::Radians => (2.0, true),
+            AngleUnit::Degrees => (360.0, false),
+            AngleUnit::Gradians => (400.0, false),
+            AngleUnit::Turns => (1.0, false),
+            AngleUnit::Milliradians => (2000.0, true),
+            AngleUnit::Arcminutes => (21600.0, false),
+            AngleUnit::Arcseconds => (1296000.0, false),
+        }
+    }
+
+    /// Returns one full turn in this unit.
+    fn full_turn<T: Float>(&self) -> T {
+        match self.per_turn() {
+            (turn, true) => T::PI * T::from_f64(turn),
+            (turn, false) => T::from_f64(turn),
+        }
+    }
+}
+
+/// Converts an angle value from one unit to another. Whole-number ratios, such as the 3600
+/// arcseconds in a degree, are applied exactly, and converting between degrees and radians gives
+/// the same result as `degreesto_radians` and `radianto_degrees`.
+///
+/// # Arguments
+///
+/// * `value` - The value of the angle in `from`.
+/// * `from` - The unit `value` is expressed in.
+/// * `to` - The unit to convert to.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::angle::* ;
+///
+/// assert_eq!(convert_angle(0.25, AngleUnit::Turns, AngleUnit::Degrees), 90.0);
+/// assert_eq!(convert_angle(1.5, AngleUnit::Degrees, AngleUnit::Arcminutes), 90.0);
+/// assert_eq!(convert_angle(0.5, AngleUnit::Turns, AngleUnit::Radians), std::f64::consts::PI);
+/// ```
+pub fn convert_angle<T: Float>(value: T, from: AngleUnit, to: AngleUnit) -> T {
+    if from == to {
+        return value;
+    }
+    let ((a, a_pi), (b, b_pi)) = (from.per_turn(), to.per_turn());
+    match (a_pi, b_pi) {
+        (false, true) => value * (T::PI / T::from_f64(a / b)),
+        (true, false) => value * (T::from_f64(b / a) / T::PI),
+        _ if b >= a => value * T::from_f64(b / a),
+        _ => value / T::from_f64(a / b),
+    }
 }
 
 /// Converts degrees to radians.
@@ -100,8 +238,8 @@ impl Degree {
 /// let radian = degreesto_radians(180.0);
 /// assert_eq!(radian.value, std::f64::consts::PI);
 /// ```
-pub fn degreesto_radians(d: f64) -> Radian {
-    let value = d * (PI / 180.0);
+pub fn degreesto_radians<T: Float>(d: T) -> Radian<T> {
+    let value = d * (T::PI / T::from_f64(180.0));
     Radian { value }
 }
 
@@ -118,8 +256,791 @@ pub fn degreesto_radians(d: f64) -> Radian {
 /// let degree = radianto_degrees(std::f64::consts::PI);
 /// assert_eq!(degree.value, 180.0);
 /// ```
-pub fn radianto_degrees(r: f64) -> Degree {
-    let value = r * (180.0 / PI);
+pub fn radianto_degrees<T: Float>(r: T) -> Degree<T> {
+    let value = r * (T::from_f64(180.0) / T::PI);
     Degree { value }
 }
- 
+
+/// Returns the sine and cosine of an angle in degrees. The angle is reduced exactly to within 45° of a
+/// multiple of 90° before converting to radians, so quarter turns give exact zeros and ones.
+fn sin_cos_degrees<T: Float>(d: T) -> (T, T) {
+    sin_cos_exact(d, AngleUnit::Degrees)
+}
+
+/// Returns the sine and cosine of an angle in a unit with a whole number of units to the turn,
+/// reducing it exactly to within an eighth of a turn of a quarter turn first.
+fn sin_cos_exact<T: Float>(value: T, unit: AngleUnit) -> (T, T) {
+    let turn: T = unit.full_turn();
+    let quarter = turn / T::from_f64(4.0);
+    let reduced = value % turn;
+    let quadrant = (reduced / quarter).round();
+    let x = convert_angle(reduced - quadrant * quarter, unit, AngleUnit::Radians);
+    let (sin, cos) = (x.sin(), x.cos());
+    // Adding zero turns the `-0` produced by negating an exact zero into `+0`.
+    match (quadrant.to_f64() as i64).rem_euclid(4) {
+        0 => (sin, cos),
+        1 => (cos, -sin + T::ZERO),
+        2 => (-sin + T::ZERO, -cos + T::ZERO),
+        _ => (-cos + T::ZERO, sin),
+    }
+}
+
+/// Implements addition, subtraction, negation and scaling for a single-unit angle type.
+macro_rules! unit_ops {
+    ($unit:ident) => {
+        impl<T: Float> Add for $unit<T> {
+            type Output = $unit<T>;
+            fn add(self, rhs: $unit<T>) -> $unit<T> {
+                $unit::from(self.value + rhs.value)
+            }
+        }
+        impl<T: Float> Sub for $unit<T> {
+            type Output = $unit<T>;
+            fn sub(self, rhs: $unit<T>) -> $unit<T> {
+                $unit::from(self.value - rhs.value)
+            }
+        }
+        impl<T: Float> Neg for $unit<T> {
+            type Output = $unit<T>;
+            fn neg(self) -> $unit<T> {
+                $unit::from(-self.value)
+            }
+        }
+        impl<T: Float> Mul<T> for $unit<T> {
+            type Output = $unit<T>;
+            fn mul(self, rhs: T) -> $unit<T> {
+                $unit::from(self.value * rhs)
+            }
+        }
+        impl<T: Float> Div<T> for $unit<T> {
+            type Output = $unit<T>;
+            fn div(self, rhs: T) -> $unit<T> {
+                $unit::from(self.value / rhs)
+            }
+        }
+        impl<T: Float> AddAssign for $unit<T> {
+            fn add_assign(&mut self, rhs: $unit<T>) {
+                self.value += rhs.value;
+            }
+        }
+        impl<T: Float> SubAssign for $unit<T> {
+            fn sub_assign(&mut self, rhs: $unit<T>) {
+                self.value -= rhs.value;
+            }
+        }
+        impl<T: Float> MulAssign<T> for $unit<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                self.value *= rhs;
+            }
+        }
+        impl<T: Float> DivAssign<T> for $unit<T> {
+            fn div_assign(&mut self, rhs: T) {
+                self.value /= rhs;
+            }
+        }
+    };
+}
+
+unit_ops!(Radian);
+unit_ops!(Degree);
+
+/// Returns the values of two angles in a common unit: their own if they share one, radians otherwise.
+fn common<T: Float>(a: &Angle<T>, b: &Angle<T>) -> (T, T, AngleUnit) {
+    if a.unit == b.unit {
+        (a.value, b.value, a.unit)
+    } else {
+        (a.radians(), b.radians(), AngleUnit::Radians)
+    }
+}
+
+/// Adds two angles, in their shared unit or in radians if the units differ.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::*;
+///
+/// let sum = Angle::from_degrees(30.0) + Angle::from_degrees(60.0);
+/// assert_eq!(sum.degrees(), 90.0);
+///
+/// let mixed = Angle::from_degrees(180.0) + Angle::from_radians(std::f64::consts::PI);
+/// assert_eq!(mixed.radians(), 2.0 * std::f64::consts::PI);
+/// ```
+impl<T: Float> Add for Angle<T> {
+    type Output = Angle<T>;
+    fn add(self, rhs: Angle<T>) -> Angle<T> {
+        let (a, b, unit) = common(&self, &rhs);
+        Angle { value: a + b, unit }
+    }
+}
+
+/// Subtracts two angles, in their shared unit or in radians if the units differ.
+impl<T: Float> Sub for Angle<T> {
+    type Output = Angle<T>;
+    fn sub(self, rhs: Angle<T>) -> Angle<T> {
+        let (a, b, unit) = common(&self, &rhs);
+        Angle { value: a - b, unit }
+    }
+}
+
+impl<T: Float> Neg for Angle<T> {
+    type Output = Angle<T>;
+    fn neg(self) -> Angle<T> {
+        Angle { value: -self.value, unit: self.unit }
+    }
+}
+
+/// Scales an angle, keeping its unit.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::*;
+///
+/// let angle = Angle::from_degrees(15.0_f64);
+/// assert_eq!((angle * 3.0).degrees(), 45.0);
+/// assert_eq!((2.0 * angle).degrees(), 30.0);
+/// assert_eq!((angle / 3.0).degrees(), 5.0);
+/// ```
+impl<T: Float> Mul<T> for Angle<T> {
+    type Output = Angle<T>;
+    fn mul(self, rhs: T) -> Angle<T> {
+        Angle { value: self.value * rhs, unit: self.unit }
+    }
+}
+
+impl<T: Float> Div<T> for Angle<T> {
+    type Output = Angle<T>;
+    fn div(self, rhs: T) -> Angle<T> {
+        Angle { value: self.value / rhs, unit: self.unit }
+    }
+}
+
+impl<T: Float> AddAssign for Angle<T> {
+    fn add_assign(&mut self, rhs: Angle<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Float> SubAssign for Angle<T> {
+    fn sub_assign(&mut self, rhs: Angle<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Float> MulAssign<T> for Angle<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.value *= rhs;
+    }
+}
+
+impl<T: Float> DivAssign<T> for Angle<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.value /= rhs;
+    }
+}
+
+/// Implements scaling with a primitive scalar on the left. Coherence rules forbid a blanket
+/// `impl<T> Mul<Angle<T>> for T`, so this is instantiated once per primitive type.
+macro_rules! scalar_lhs_mul {
+    ($t:ty) => {
+        impl Mul<Angle<$t>> for $t {
+            type Output = Angle<$t>;
+            fn mul(self, rhs: Angle<$t>) -> Angle<$t> {
+                rhs * self
+            }
+        }
+        impl Mul<Radian<$t>> for $t {
+            type Output = Radian<$t>;
+            fn mul(self, rhs: Radian<$t>) -> Radian<$t> {
+                rhs * self
+            }
+        }
+        impl Mul<Degree<$t>> for $t {
+            type Output = Degree<$t>;
+            fn mul(self, rhs: Degree<$t>) -> Degree<$t> {
+                rhs * self
+            }
+        }
+    };
+}
+
+scalar_lhs_mul!(f32);
+scalar_lhs_mul!(f64);
+
+/// Compares two angles by their value, so `180°` equals `π rad`. Units that are both rational
+/// fractions of a turn (degrees, minutes, seconds, gradians and turns), or both multiples of `π`
+/// (radians and milliradians), are compared exactly by cross-multiplying with whole-number ratios, so
+/// `45°` equals `50 grad`. Between the two families the comparison is made in radians, where rounding
+/// can make mathematically equal angles differ in the last place; compare those with `abs_diff_eq`
+/// on their radian values instead.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::{*, angle::AngleUnit};
+///
+/// assert_eq!(Angle::from_degrees(180.0), Angle::from_radians(std::f64::consts::PI));
+/// assert!(Angle::from_degrees(45.0) < Angle::from_radians(1.0));
+/// assert_eq!(Angle::from_degrees(45.0), Angle::new(50.0, AngleUnit::Gradians));
+/// assert_eq!(Angle::new(0.1, AngleUnit::Turns), Angle::from_degrees(36.0));
+/// assert!(Angle::new(30.0, AngleUnit::Arcminutes) < Angle::from_degrees(0.6));
+/// ```
+impl<T: Float> PartialEq for Angle<T> {
+    fn eq(&self, other: &Angle<T>) -> bool {
+        let (a, b) = comparable(self, other);
+        a == b
+    }
+}
+
+impl<T: Float> PartialOrd for Angle<T> {
+    fn partial_cmp(&self, other: &Angle<T>) -> Option<Ordering> {
+        let (a, b) = comparable(self, other);
+        a.partial_cmp(&b)
+    }
+}
+
+/// Returns values proportional to two angles that compare as the angles do. Units of the same family
+/// are scaled by the reduced ratio of their units per turn, which is exact for equal angles since
+/// both products round the same real number; other pairs are converted to radians.
+fn comparable<T: Float>(a: &Angle<T>, b: &Angle<T>) -> (T, T) {
+    let ((a_turn, a_pi), (b_turn, b_pi)) = (a.unit.per_turn(), b.unit.per_turn());
+    if a.unit == b.unit {
+        (a.value, b.value)
+    } else if a_pi == b_pi {
+        let (mut x, mut y) = (a_turn as u64, b_turn as u64);
+        while y != 0 {
+            (x, y) = (y, x % y);
+        }
+        let divisor = x as f64;
+        (a.value * T::from_f64(b_turn / divisor), b.value * T::from_f64(a_turn / divisor))
+    } else {
+        (a.radians(), b.radians())
+    }
+}
+
+impl<T: Float> Angle<T> {
+    /// Returns the sine of the angle. Angles in any unit but radians and milliradians give exact
+    /// results at quarter turns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(Angle::from_degrees(90.0).sin(), 1.0);
+    /// assert_eq!(Angle::from_degrees(180.0).sin(), 0.0);
+    /// ```
+    pub fn sin(&self) -> T {
+        self.sin_cos().0
+    }
+
+    /// Returns the cosine of the angle. Angles in any unit but radians and milliradians give exact
+    /// results at quarter turns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(Angle::from_degrees(90.0).cos(), 0.0);
+    /// assert_eq!(Angle::from_radians(0.0).cos(), 1.0);
+    /// ```
+    pub fn cos(&self) -> T {
+        self.sin_cos().1
+    }
+
+    /// Returns the tangent of the angle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(Angle::from_degrees(180.0).tan(), 0.0);
+    /// assert!(Angle::from_degrees(90.0_f64).tan().is_infinite());
+    /// ```
+    pub fn tan(&self) -> T {
+        match self.unit {
+            AngleUnit::Radians | AngleUnit::Milliradians => self.radians().tan(),
+            _ => {
+                let (sin, cos) = self.sin_cos();
+                sin / cos
+            }
+        }
+    }
+
+    /// Returns the sine and the cosine of the angle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let (sin, cos) = Angle::from_degrees(-90.0).sin_cos();
+    /// assert_eq!((sin, cos), (-1.0, 0.0));
+    /// ```
+    pub fn sin_cos(&self) -> (T, T) {
+        match self.unit {
+            AngleUnit::Radians | AngleUnit::Milliradians => {
+                let radians = self.radians();
+                (radians.sin(), radians.cos())
+            }
+            unit => sin_cos_exact(self.value, unit),
+        }
+    }
+
+    /// Creates the angle whose sine is `x`, in radians within `[-π/2, π/2]`. Returns NaN if `x` is
+    /// outside `[-1, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(Angle::asin(1.0).radians(), std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn asin(x: T) -> Angle<T> {
+        Angle::from_radians(x.asin())
+    }
+
+    /// Creates the angle whose cosine is `x`, in radians within `[0, π]`. Returns NaN if `x` is
+    /// outside `[-1, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(Angle::acos(-1.0).radians(), std::f64::consts::PI);
+    /// ```
+    pub fn acos(x: T) -> Angle<T> {
+        Angle::from_radians(x.acos())
+    }
+
+    /// Creates the angle whose tangent is `x`, in radians within `(-π/2, π/2)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(Angle::atan(1.0).radians(), std::f64::consts::FRAC_PI_4);
+    /// ```
+    pub fn atan(x: T) -> Angle<T> {
+        Angle::from_radians(x.atan())
+    }
+
+    /// Creates the angle of the point `(x, y)`, in radians within `(-π, π]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - The vertical coordinate.
+    /// * `x` - The horizontal coordinate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(Angle::atan2(-1.0, -1.0).degrees(), -135.0);
+    /// ```
+    pub fn atan2(y: T, x: T) -> Angle<T> {
+        Angle::from_radians(y.atan2(x))
+    }
+
+    /// Returns the absolute value of the angle, keeping its unit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(Angle::from_degrees(-30.0).abs().degrees(), 30.0);
+    /// ```
+    pub fn abs(&self) -> Angle<T> {
+        Angle { value: self.value.abs(), unit: self.unit }
+    }
+
+    /// Wraps the angle into `[0, 2π)`, or one turn from zero in the angle's own unit, such as
+    /// `[0°, 360°)` for an angle in degrees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(Angle::from_degrees(-90.0).wrap_positive().degrees(), 270.0);
+    /// assert_eq!(Angle::from_degrees(720.0).wrap_positive().degrees(), 0.0);
+    /// ```
+    pub fn wrap_positive(&self) -> Angle<T> {
+        Angle { value: wrap_from(self.value, T::ZERO, self.unit.full_turn()), unit: self.unit }
+    }
+
+    /// Wraps the angle into `(-π, π]`, or the half turn either side of zero in the angle's own unit,
+    /// such as `(-180°, 180°]` for an angle in degrees. This is the range
+    /// that `ComplexNumber::angle_in_rads` and `ComplexNumber::angle_in_degs` report.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(Angle::from_degrees(390.0).wrap_signed().degrees(), 30.0);
+    /// assert_eq!(Angle::from_degrees(-180.0).wrap_signed().degrees(), 180.0);
+    ///
+    /// let z = ComplexNumber::from_polar(1.0_f64, Angle::from_degrees(130.0)).powi(3);
+    /// assert!((z.angle_in_degs() - Angle::from_degrees(390.0).wrap_signed().degrees()).abs() < 1e-12);
+    /// ```
+    pub fn wrap_signed(&self) -> Angle<T> {
+        let turn = self.unit.full_turn();
+        let half = turn / T::from_f64(2.0);
+        let mut value = self.value % turn;
+        if value > half {
+            value -= turn;
+        } else if value <= -half {
+            value += turn;
+        }
+        Angle { value, unit: self.unit }
+    }
+
+    /// Wraps the angle into the one-turn interval `[start, start + 2π)`, keeping its unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The lower, inclusive end of the interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let start = Angle::from_degrees(-90.0);
+    /// assert_eq!(Angle::from_degrees(300.0).wrap_from(start).degrees(), -60.0);
+    /// assert_eq!(Angle::from_degrees(250.0).wrap_from(start).degrees(), 250.0);
+    /// ```
+    pub fn wrap_from(&self, start: Angle<T>) -> Angle<T> {
+        let start = start.in_unit(self.unit);
+        Angle { value: wrap_from(self.value, start, self.unit.full_turn()), unit: self.unit }
+    }
+
+    /// Returns the signed shortest rotation from `other` to this angle, within `(-π, π]`. Adding the
+    /// result to `other` gives an angle coterminal with this one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The angle to measure from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let a = Angle::from_degrees(10.0);
+    /// let b = Angle::from_degrees(350.0);
+    /// assert_eq!(a.shortest_difference(&b).degrees(), 20.0);
+    /// assert_eq!(b.shortest_difference(&a).degrees(), -20.0);
+    /// ```
+    pub fn shortest_difference(&self, other: &Angle<T>) -> Angle<T> {
+        (*self - *other).wrap_signed()
+    }
+
+    /// Returns `true` if this angle and `other` differ by a whole number of turns, within `tolerance`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The angle to compare with.
+    /// * `tolerance` - The largest accepted shortest difference between the two angles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let tolerance = Angle::from_radians(1e-12);
+    /// assert!(Angle::from_degrees(-270.0).is_coterminal(&Angle::from_degrees(90.0), tolerance));
+    /// assert!(Angle::from_radians(2.5 * std::f64::consts::PI)
+    ///     .is_coterminal(&Angle::from_degrees(90.0), tolerance));
+    /// assert!(!Angle::from_degrees(90.0).is_coterminal(&Angle::from_degrees(-90.0), tolerance));
+    /// ```
+    pub fn is_coterminal(&self, other: &Angle<T>, tolerance: Angle<T>) -> bool {
+        self.shortest_difference(other).abs() <= tolerance
+    }
+}
+
+/// Wraps `value` into `[start, start + turn)`.
+fn wrap_from<T: Float>(value: T, start: T, turn: T) -> T {
+    let mut offset = (value - start) % turn;
+    if offset < T::ZERO {
+        offset += turn;
+    }
+    // A tiny negative offset can round up to a whole turn.
+    if offset >= turn {
+        offset = T::ZERO;
+    }
+    start + offset
+}
+
+/// Unwraps a sequence of phases in radians by removing the whole turns added or lost wherever two
+/// consecutive samples jump by more than `threshold`. The first sample is kept as is.
+///
+/// # Arguments
+///
+/// * `phase` - The wrapped phase samples, in radians.
+/// * `threshold` - The largest jump accepted as genuine; `π` is the usual choice.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::angle::* ;
+///
+/// let wrapped = [2.5, 3.0, -3.0, -2.5];
+/// let unwrapped = unwrap_radians(&wrapped, std::f64::consts::PI);
+/// let turn = 2.0 * std::f64::consts::PI;
+/// assert_eq!(unwrapped, vec![2.5, 3.0, -3.0 + turn, -2.5 + turn]);
+/// ```
+pub fn unwrap_radians<T: Float>(phase: &[T], threshold: T) -> Vec<T> {
+    let turn = T::PI + T::PI;
+    let mut offset = T::ZERO;
+    let mut previous = None;
+    let mut unwrapped = Vec::with_capacity(phase.len());
+    for &sample in phase {
+        if let Some(previous) = previous {
+            let jump = sample - previous;
+            if jump.abs() > threshold {
+                offset -= turn * (jump / turn).round();
+            }
+        }
+        previous = Some(sample);
+        unwrapped.push(sample + offset);
+    }
+    unwrapped
+}
+
+/// Unwraps a sequence of angles, as `unwrap_radians` does. The result is in radians.
+///
+/// # Arguments
+///
+/// * `phase` - The wrapped phase samples.
+/// * `threshold` - The largest jump accepted as genuine; 180° is the usual choice.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::{*, angle::*};
+///
+/// let sweep: Vec<Angle> = [170.0, -170.0, -150.0].iter().map(|&d| Angle::from_degrees(d)).collect();
+/// let unwrapped = unwrap_angles(&sweep, Angle::from_degrees(180.0));
+/// assert!((unwrapped[1].degrees() - 190.0).abs() < 1e-12);
+/// assert!((unwrapped[2].degrees() - 210.0).abs() < 1e-12);
+/// ```
+pub fn unwrap_angles<T: Float>(phase: &[Angle<T>], threshold: Angle<T>) -> Vec<Angle<T>> {
+    let radians: Vec<T> = phase.iter().map(|angle| angle.radians()).collect();
+    unwrap_radians(&radians, threshold.radians())
+        .into_iter()
+        .map(Angle::from_radians)
+        .collect()
+}
+
+/// Unwraps a two-dimensional phase map in radians, stored row by row. The first column is unwrapped
+/// first, then each row is unwrapped starting from its entry in that column. This path-following
+/// scheme is exact for smooth maps but spreads any error caused by noise along the path.
+///
+/// # Arguments
+///
+/// * `phase` - The wrapped phase map, in radians, in row-major order.
+/// * `columns` - The number of samples in each row.
+/// * `threshold` - The largest jump accepted as genuine; `π` is the usual choice.
+///
+/// # Panics
+///
+/// Panics if `columns` is zero or does not divide the length of `phase`.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::angle::* ;
+///
+/// let pi = std::f64::consts::PI;
+/// let wrap = |x: f64| (x + pi).rem_euclid(2.0 * pi) - pi;
+/// let map: Vec<f64> = (0..4)
+///     .flat_map(|row| (0..5).map(move |column| 1.1 * row as f64 + 0.9 * column as f64))
+///     .collect();
+/// let wrapped: Vec<f64> = map.iter().map(|&x| wrap(x)).collect();
+/// let unwrapped = unwrap_radians_2d(&wrapped, 5, pi);
+/// for (a, b) in unwrapped.iter().zip(&map) {
+///     assert!((a - b).abs() < 1e-12);
+/// }
+/// ```
+pub fn unwrap_radians_2d<T: Float>(phase: &[T], columns: usize, threshold: T) -> Vec<T> {
+    assert!(
+        columns > 0 && phase.chunks_exact(columns).remainder().is_empty(),
+        "a phase map of {} samples cannot have {} columns",
+        phase.len(),
+        columns
+    );
+    let first_column: Vec<T> = phase.iter().step_by(columns).copied().collect();
+    let starts = unwrap_radians(&first_column, threshold);
+    let mut unwrapped = Vec::with_capacity(phase.len());
+    for (row, start) in phase.chunks(columns).zip(starts) {
+        let row = unwrap_radians(row, threshold);
+        let shift = start - row[0];
+        unwrapped.extend(row.into_iter().map(|sample| sample + shift));
+    }
+    unwrapped
+}
+
+/// Computes the group delay `-dφ/dω` of an unwrapped phase response. Interior points use central
+/// differences and the two end points use one-sided differences, so the frequencies do not have to
+/// be evenly spaced.
+///
+/// # Arguments
+///
+/// * `phase` - The unwrapped phase, in radians.
+/// * `omega` - The angular frequencies the phase was sampled at, in increasing order.
+///
+/// # Panics
+///
+/// Panics if `phase` and `omega` have different lengths, or fewer than two samples.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::angle::* ;
+///
+/// // A pure delay of 2.5 samples has the phase response -2.5ω.
+/// let omega: Vec<f64> = (0..64).map(|k| k as f64 * 0.05).collect();
+/// let wrapped: Vec<f64> = omega.iter().map(|w| (-2.5 * w).sin().atan2((-2.5 * w).cos())).collect();
+/// let delay = group_delay(&unwrap_radians(&wrapped, std::f64::consts::PI), &omega);
+/// assert!(delay.iter().all(|d| (d - 2.5).abs() < 1e-9));
+/// ```
+pub fn group_delay<T: Float>(phase: &[T], omega: &[T]) -> Vec<T> {
+    assert_eq!(phase.len(), omega.len(), "phase and frequency lengths differ");
+    assert!(phase.len() >= 2, "group delay needs at least two samples");
+    let last = phase.len() - 1;
+    (0..=last)
+        .map(|i| {
+            let (before, after) = (i.saturating_sub(1), (i + 1).min(last));
+            -(phase[after] - phase[before]) / (omega[after] - omega[before])
+        })
+        .collect()
+}
+
+/// An angle in degrees, minutes and seconds of arc, as returned by `Angle::to_dms`.
+///
+/// `Display` writes `12°34'56.7"`. A precision (`{:.1}`) applies to the seconds, carrying into the
+/// minutes and degrees when they round up to 60.
+///
+/// # Fields
+///
+/// * `negative` - Whether the angle is below zero.
+/// * `degrees` - The whole degrees.
+/// * `minutes` - The whole minutes, below 60.
+/// * `seconds` - The seconds, below 60.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::*;
+///
+/// let dms = Angle::from_dms(-12.0, 34.0, 56.7).to_dms();
+/// assert!(dms.negative);
+/// assert_eq!((dms.degrees, dms.minutes), (12.0, 34.0));
+/// assert_eq!(format!("{:.1}", dms), "-12°34'56.7\"");
+/// assert_eq!(format!("{:.0}", Angle::from_degrees(29.99999_f64).to_dms()), "30°0'0\"");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dms<T = f64> {
+    pub negative: bool,
+    pub degrees: T,
+    pub minutes: T,
+    pub seconds: T,
+}
+impl<T: Float> Dms<T> {
+    /// Converts the degrees, minutes and seconds back to an `Angle`, stored in arcseconds.
+    pub fn to_angle(&self) -> Angle<T> {
+        let sixty = T::from_f64(60.0);
+        let value = (self.degrees * sixty + self.minutes) * sixty + self.seconds;
+        Angle { value: if self.negative { -value } else { value }, unit: AngleUnit::Arcseconds }
+    }
+}
+impl<T: Float> fmt::Display for Dms<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sixty = T::from_f64(60.0);
+        let (mut degrees, mut minutes, mut seconds) = (self.degrees, self.minutes, self.seconds);
+        if let Some(precision) = f.precision() {
+            let scale = T::from_f64(10f64.powi(precision as i32));
+            seconds = (seconds * scale).round() / scale;
+            if seconds >= sixty {
+                seconds -= sixty;
+                minutes += T::ONE;
+            }
+            if minutes >= sixty {
+                minutes -= sixty;
+                degrees += T::ONE;
+            }
+        }
+        let sign = if self.negative { "-" } else { "" };
+        match f.precision() {
+            Some(precision) => write!(f, "{}{}°{}'{:.*}\"", sign, degrees, minutes, precision, seconds),
+            None => write!(f, "{}{}°{}'{}\"", sign, degrees, minutes, seconds),
+        }
+    }
+}
+
+impl<T: Float> Angle<T> {
+    /// Creates an angle from degrees, minutes and seconds of arc, stored in arcseconds so that whole
+    /// seconds are exact. The sign of `degrees` applies to the whole angle, so `-0.0` degrees and
+    /// 30 minutes is half a degree below zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `degrees` - The whole degrees, carrying the sign of the angle.
+    /// * `minutes` - The minutes of arc.
+    /// * `seconds` - The seconds of arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(Angle::from_dms(12.0, 30.0, 0.0).degrees(), 12.5);
+    /// assert_eq!(Angle::from_dms(-0.0, 30.0, 0.0).degrees(), -0.5);
+    /// ```
+    pub fn from_dms(degrees: T, minutes: T, seconds: T) -> Angle<T> {
+        let negative = T::ONE.copysign(degrees) < T::ZERO;
+        Dms { negative, degrees: degrees.abs(), minutes, seconds }.to_angle()
+    }
+
+    /// Splits the angle into degrees, minutes and seconds of arc.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let dms = Angle::from_degrees(12.5125_f64).to_dms();
+    /// assert_eq!((dms.degrees, dms.minutes), (12.0, 30.0));
+    /// assert!((dms.seconds - 45.0).abs() < 1e-9);
+    /// assert_eq!(Angle::from_degrees(90.0).to_dms().to_string(), "90°0'0\"");
+    /// ```
+    pub fn to_dms(&self) -> Dms<T> {
+        let sixty = T::from_f64(60.0);
+        let total = self.in_unit(AngleUnit::Arcseconds);
+        let negative = total < T::ZERO;
+        let total = total.abs();
+        let degrees = (total / (sixty * sixty)).trunc();
+        let rest = total - degrees * sixty * sixty;
+        let minutes = (rest / sixty).trunc();
+        Dms { negative, degrees, minutes, seconds: rest - minutes * sixty }
+    }
+}
+
+/// A `Radian` with an `f32` value.
+pub type Radian32 = Radian<f32>;
+/// A `Radian` with an `f64` value.
+pub type Radian64 = Radian<f64>;
+/// A `Degree` with an `f32` value.
+pub type Degree32 = Degree<f32>;
+/// A `Degree` with an `f64` value.
+pub type Degree64 = Degree<f64>;
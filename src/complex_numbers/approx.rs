@@ -0,0 +1,186 @@
+//! # `approx` Module
+//!
+//! This module provides tolerance-based comparison of complex numbers. `PartialEq` on `ComplexNumber`
+//! is exact component equality, which is rarely what a numerical test wants; the methods here compare
+//! with an explicit absolute, relative or ULP (units in the last place) tolerance instead.
+//!
+//! The `assert_approx_eq!` macro wraps them for use in tests:
+//!
+//! ```
+//! use complexible::assert_approx_eq;
+//! use complexible::complex_numbers::*;
+//!
+//! let z = ComplexNumber::from_polar(2.0, Angle::from_degrees(90.0));
+//! assert_approx_eq!(z, ComplexNumber::from_cartesian(0.0, 2.0), abs = 1e-15);
+//! assert_approx_eq!(z.mul(&z), ComplexNumber::from_real(-4.0), rel = 1e-15);
+//! ```
+
+use super::*;
+
+/// A tolerance for `ComplexNumber::approx_eq`.
+///
+/// # Variants
+///
+/// * `Absolute(epsilon)` - The distance `|a - b|` must not exceed `epsilon`.
+/// * `Relative(max_relative)` - The distance `|a - b|` must not exceed `max_relative · max(|a|, |b|)`.
+/// * `Ulps(max_ulps)` - Each component of `a` must be within `max_ulps` representable values of the
+///   same component of `b`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance<T = f64> {
+    Absolute(T),
+    Relative(T),
+    Ulps(u64),
+}
+
+impl<T: Float> ComplexNumber<T> {
+    /// Returns `true` if the distance between this complex number and `other` is at most `epsilon`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the complex number to compare with.
+    /// * `epsilon` - The largest accepted distance `|a - b|`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(1e-7, 0.0);
+    /// let z2 = ComplexNumber::from_real(0.0);
+    /// assert!(z1.abs_diff_eq(&z2, 1e-6));
+    /// assert!(!z1.abs_diff_eq(&z2, 1e-8));
+    /// ```
+    pub fn abs_diff_eq(&self, other: &ComplexNumber<T>, epsilon: T) -> bool {
+        if self.real() == other.real() && self.imag() == other.imag() {
+            return true;
+        }
+        distance(self, other) <= epsilon
+    }
+
+    /// Returns `true` if the distance between this complex number and `other` is at most `max_relative`
+    /// times the larger of their magnitudes.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the complex number to compare with.
+    /// * `max_relative` - The largest accepted distance, relative to `max(|a|, |b|)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(1e10, 1.0);
+    /// let z2 = ComplexNumber::from_cartesian(1e10 + 1.0, 1.0);
+    /// assert!(z1.relative_eq(&z2, 1e-9));
+    /// assert!(!z1.relative_eq(&z2, 1e-11));
+    /// ```
+    pub fn relative_eq(&self, other: &ComplexNumber<T>, max_relative: T) -> bool {
+        if self.real() == other.real() && self.imag() == other.imag() {
+            return true;
+        }
+        let a = self.real().hypot(self.imag());
+        let b = other.real().hypot(other.imag());
+        let largest = if a > b { a } else { b };
+        distance(self, other) <= max_relative * largest
+    }
+
+    /// Returns `true` if the real parts and the imaginary parts of this complex number and `other` are
+    /// each at most `max_ulps` representable values apart.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the complex number to compare with.
+    /// * `max_ulps` - The largest accepted number of units in the last place between components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(0.1 + 0.2, 1.0);
+    /// let z2 = ComplexNumber::from_cartesian(0.3, 1.0);
+    /// assert!(z1 != z2);
+    /// assert!(z1.ulps_eq(&z2, 1));
+    /// ```
+    pub fn ulps_eq(&self, other: &ComplexNumber<T>, max_ulps: u64) -> bool {
+        self.real().ulps_between(other.real()) <= max_ulps
+            && self.imag().ulps_between(other.imag()) <= max_ulps
+    }
+
+    /// Returns `true` if this complex number equals `other` within the given tolerance.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - A reference to the complex number to compare with.
+    /// * `tolerance` - The absolute, relative or ULP tolerance to compare with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, approx::Tolerance};
+    ///
+    /// let z1 = ComplexNumber::from_polar(1.0, Angle::from_degrees(60.0));
+    /// let z2 = ComplexNumber::from_cartesian(0.5, 3.0_f64.sqrt() / 2.0);
+    /// assert!(z1.approx_eq(&z2, Tolerance::Absolute(1e-15)));
+    /// assert!(z1.approx_eq(&z2, Tolerance::Relative(1e-15)));
+    /// assert!(z1.approx_eq(&z2, Tolerance::Ulps(4)));
+    /// ```
+    pub fn approx_eq(&self, other: &ComplexNumber<T>, tolerance: Tolerance<T>) -> bool {
+        match tolerance {
+            Tolerance::Absolute(epsilon) => self.abs_diff_eq(other, epsilon),
+            Tolerance::Relative(max_relative) => self.relative_eq(other, max_relative),
+            Tolerance::Ulps(max_ulps) => self.ulps_eq(other, max_ulps),
+        }
+    }
+}
+
+fn distance<T: Float>(a: &ComplexNumber<T>, b: &ComplexNumber<T>) -> T {
+    (a.real() - b.real()).hypot(a.imag() - b.imag())
+}
+
+/// Asserts that two complex numbers are equal within an absolute (`abs`), relative (`rel`) or ULP
+/// (`ulps`) tolerance, printing both values on failure.
+///
+/// # Example
+///
+/// ```
+/// use complexible::assert_approx_eq;
+/// use complexible::complex_numbers::*;
+///
+/// let z = ComplexNumber::from_cartesian(3.0, 4.0);
+/// assert_approx_eq!(z.sqrt().powi(2), z, abs = 1e-15);
+/// assert_approx_eq!(z.ln().exp(), z, rel = 1e-15);
+/// assert_approx_eq!(z.sqrt(), ComplexNumber::from_cartesian(2.0, 1.0), ulps = 2);
+/// ```
+///
+/// ```should_panic
+/// use complexible::assert_approx_eq;
+/// use complexible::complex_numbers::*;
+///
+/// assert_approx_eq!(ComplexNumber::from_real(1.0), ComplexNumber::from_real(1.1), abs = 1e-3);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    (@check $left:expr, $right:expr, $tolerance:expr) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if !left.approx_eq(right, tolerance) {
+                    panic!(
+                        "assertion `left ≈ right` failed ({:?})\n  left: {}\n right: {}",
+                        tolerance, left, right,
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, abs = $epsilon:expr $(,)?) => {
+        $crate::assert_approx_eq!(@check $left, $right, $crate::complex_numbers::approx::Tolerance::Absolute($epsilon))
+    };
+    ($left:expr, $right:expr, rel = $max_relative:expr $(,)?) => {
+        $crate::assert_approx_eq!(@check $left, $right, $crate::complex_numbers::approx::Tolerance::Relative($max_relative))
+    };
+    ($left:expr, $right:expr, ulps = $max_ulps:expr $(,)?) => {
+        $crate::assert_approx_eq!(@check $left, $right, $crate::complex_numbers::approx::Tolerance::Ulps($max_ulps))
+    };
+}
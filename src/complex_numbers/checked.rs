@@ -0,0 +1,365 @@
+//! # `checked` Module
+//!
+//! This module provides the predicates `is_nan`, `is_finite`, `is_infinite` and `is_zero` on
+//! `ComplexNumber`, and checked variants of the operations that can silently produce infinities or
+//! NaNs. Each checked variant returns a `Result` with a `ComplexError` describing why the operation
+//! was degenerate, instead of a value that would propagate through the rest of a computation.
+//!
+//! A checked operation fails when an operand is infinite or NaN, when the operation is undefined for
+//! its operands (dividing by zero, the logarithm of zero), or when finite operands give a result that
+//! is not finite.
+//!
+//! ```
+//! use complexible::complex_numbers::{*, checked::ComplexError};
+//!
+//! let z = ComplexNumber::from_cartesian(3.0, 4.0);
+//! let zero = ComplexNumber::from_real(0.0);
+//! assert_eq!(z.checked_div(&zero), Err(ComplexError::DivisionByZero));
+//! assert_eq!(zero.checked_ln(), Err(ComplexError::LogarithmOfZero));
+//! assert_eq!(z.checked_log(1.0), Err(ComplexError::InvalidBase));
+//! assert!(z.checked_div(&z).is_ok());
+//! ```
+
+use super::*;
+use std::error::Error;
+
+/// The reason a checked operation on a `ComplexNumber` failed.
+///
+/// # Variants
+///
+/// * `NotFinite` - An operand is infinite or NaN.
+/// * `DivisionByZero` - The divisor is zero, or a zero number was raised to a negative power.
+/// * `LogarithmOfZero` - The logarithm of zero was requested.
+/// * `InvalidBase` - The base of a logarithm is zero, one, negative or not finite.
+/// * `InvalidRoot` - The degree of a root is zero or not finite.
+/// * `Overflow` - Finite operands gave a result that is infinite or NaN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComplexError {
+    NotFinite,
+    DivisionByZero,
+    LogarithmOfZero,
+    InvalidBase,
+    InvalidRoot,
+    Overflow,
+}
+impl fmt::Display for ComplexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ComplexError::NotFinite => write!(f, "an operand is infinite or NaN"),
+            ComplexError::DivisionByZero => write!(f, "division by zero"),
+            ComplexError::LogarithmOfZero => write!(f, "the logarithm of zero is undefined"),
+            ComplexError::InvalidBase => write!(f, "the base of a logarithm must be positive, finite and not one"),
+            ComplexError::InvalidRoot => write!(f, "the degree of a root must be finite and non-zero"),
+            ComplexError::Overflow => write!(f, "the result is not finite"),
+        }
+    }
+}
+impl Error for ComplexError {}
+
+impl<T: Float> ComplexNumber<T> {
+    /// Returns `true` if either part is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert!(ComplexNumber::from_cartesian(1.0, f64::NAN).is_nan());
+    /// assert!(!ComplexNumber::from_cartesian(1.0, f64::INFINITY).is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool {
+        self.real().is_nan() || self.imag().is_nan()
+    }
+
+    /// Returns `true` if both parts are finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert!(ComplexNumber::from_cartesian(1.0, -2.0).is_finite());
+    /// assert!(!ComplexNumber::from_cartesian(1.0, f64::NAN).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.real().is_finite() && self.imag().is_finite()
+    }
+
+    /// Returns `true` if either part is infinite, even when the other is NaN. Following C99 Annex G,
+    /// such a number is a complex infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert!(ComplexNumber::from_cartesian(f64::NAN, f64::NEG_INFINITY).is_infinite());
+    /// assert!(!ComplexNumber::from_cartesian(f64::NAN, 0.0).is_infinite());
+    /// ```
+    pub fn is_infinite(&self) -> bool {
+        self.real().is_infinite() || self.imag().is_infinite()
+    }
+
+    /// Returns `true` if both parts are zero, of either sign.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert!(ComplexNumber::from_cartesian(-0.0, 0.0).is_zero());
+    /// assert!(!ComplexNumber::from_cartesian(0.0, 1e-300).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.real() == T::ZERO && self.imag() == T::ZERO
+    }
+
+    /// Divides this complex number by the given complex number, failing instead of returning an
+    /// infinite or NaN result.
+    ///
+    /// # Arguments
+    ///
+    /// * `z2` - A reference to the complex number to divide by.
+    ///
+    /// # Errors
+    ///
+    /// `NotFinite` if either operand is not finite, `DivisionByZero` if `z2` is zero, and `Overflow`
+    /// if the quotient is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, checked::ComplexError};
+    ///
+    /// let z = ComplexNumber::from_cartesian(0.0, 2.0);
+    /// let quotient = z.checked_div(&ComplexNumber::from_real(2.0)).unwrap();
+    /// assert!(quotient.abs_diff_eq(&ComplexNumber::from_cartesian(0.0, 1.0), 1e-15));
+    ///
+    /// let tiny = ComplexNumber::from_real(1e-300);
+    /// assert_eq!(ComplexNumber::from_real(1e300).checked_div(&tiny), Err(ComplexError::Overflow));
+    /// ```
+    pub fn checked_div(&self, z2: &ComplexNumber<T>) -> Result<ComplexNumber<T>, ComplexError> {
+        finite(self)?;
+        finite(z2)?;
+        if z2.is_zero() {
+            return Err(ComplexError::DivisionByZero);
+        }
+        result(self.div(z2))
+    }
+
+    /// Raises this complex number to a real power, failing instead of returning an infinite or NaN
+    /// result.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The power to raise this complex number to.
+    ///
+    /// # Errors
+    ///
+    /// `NotFinite` if this number or `n` is not finite, `DivisionByZero` if this number is zero and
+    /// `n` is negative, and `Overflow` if the power is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, checked::ComplexError};
+    ///
+    /// let zero = ComplexNumber::from_real(0.0);
+    /// assert_eq!(zero.checked_pow(-1.0), Err(ComplexError::DivisionByZero));
+    /// assert_eq!(ComplexNumber::from_real(10.0).checked_pow(400.0), Err(ComplexError::Overflow));
+    /// ```
+    pub fn checked_pow(&self, n: T) -> Result<ComplexNumber<T>, ComplexError> {
+        finite(self)?;
+        if !n.is_finite() {
+            return Err(ComplexError::NotFinite);
+        }
+        if self.is_zero() && n < T::ZERO {
+            return Err(ComplexError::DivisionByZero);
+        }
+        result(self.pow(n))
+    }
+
+    /// Raises this complex number to an integer power, failing instead of returning an infinite or
+    /// NaN result.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The integer power to raise this complex number to.
+    ///
+    /// # Errors
+    ///
+    /// `NotFinite` if this number is not finite, `DivisionByZero` if it is zero and `n` is negative,
+    /// and `Overflow` if the power is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z = ComplexNumber::from_cartesian(1.0, 1.0);
+    /// assert_eq!(z.checked_powi(4), Ok(ComplexNumber::from_real(-4.0)));
+    /// assert!(ComplexNumber::from_real(0.0).checked_powi(-2).is_err());
+    /// ```
+    pub fn checked_powi(&self, n: i32) -> Result<ComplexNumber<T>, ComplexError> {
+        finite(self)?;
+        if self.is_zero() && n < 0 {
+            return Err(ComplexError::DivisionByZero);
+        }
+        result(self.powi(n))
+    }
+
+    /// Calculates the principal nth root of this complex number, failing instead of returning an
+    /// infinite or NaN result.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The root to calculate.
+    ///
+    /// # Errors
+    ///
+    /// `NotFinite` if this number is not finite, `InvalidRoot` if `n` is zero or not finite,
+    /// `DivisionByZero` if this number is zero and `n` is negative, and `Overflow` if the root is not
+    /// finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, checked::ComplexError};
+    ///
+    /// let z = ComplexNumber::from_real(-8.0);
+    /// assert!(z.checked_nth_root(3.0).is_ok());
+    /// assert_eq!(z.checked_nth_root(0.0), Err(ComplexError::InvalidRoot));
+    /// ```
+    pub fn checked_nth_root(&self, n: T) -> Result<ComplexNumber<T>, ComplexError> {
+        finite(self)?;
+        if n == T::ZERO || !n.is_finite() {
+            return Err(ComplexError::InvalidRoot);
+        }
+        if self.is_zero() && n < T::ZERO {
+            return Err(ComplexError::DivisionByZero);
+        }
+        result(self.nth_root(n))
+    }
+
+    /// Calculates the principal natural logarithm of this complex number, failing for zero instead of
+    /// returning `-∞`.
+    ///
+    /// # Errors
+    ///
+    /// `NotFinite` if this number is not finite, and `LogarithmOfZero` if it is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, checked::ComplexError};
+    ///
+    /// assert_eq!(ComplexNumber::from_real(1.0).checked_ln(), Ok(ComplexNumber::from_real(0.0)));
+    /// assert_eq!(ComplexNumber::from_real(0.0).checked_ln(), Err(ComplexError::LogarithmOfZero));
+    /// ```
+    pub fn checked_ln(&self) -> Result<ComplexNumber<T>, ComplexError> {
+        finite(self)?;
+        if self.is_zero() {
+            return Err(ComplexError::LogarithmOfZero);
+        }
+        result(self.ln())
+    }
+
+    /// Calculates the principal base-10 logarithm of this complex number, failing for zero.
+    ///
+    /// # Errors
+    ///
+    /// `NotFinite` if this number is not finite, and `LogarithmOfZero` if it is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(ComplexNumber::from_real(100.0).checked_log10().unwrap().real(), 2.0);
+    /// assert!(ComplexNumber::from_real(0.0).checked_log10().is_err());
+    /// ```
+    pub fn checked_log10(&self) -> Result<ComplexNumber<T>, ComplexError> {
+        let ln = self.checked_ln()?;
+        result(ln.unscale(T::from_f64(10.0).ln()))
+    }
+
+    /// Calculates the principal logarithm of this complex number with respect to a real base,
+    /// failing for zero and for a base that has no logarithm or a zero one.
+    ///
+    /// # Arguments
+    ///
+    /// * `arb` - The base to calculate the logarithm with respect to.
+    ///
+    /// # Errors
+    ///
+    /// `NotFinite` if this number is not finite, `InvalidBase` if `arb` is zero, one, negative or not
+    /// finite, and `LogarithmOfZero` if this number is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, checked::ComplexError};
+    ///
+    /// let z = ComplexNumber::from_real(8.0);
+    /// assert_eq!(z.checked_log(2.0).unwrap().real(), 3.0);
+    /// assert_eq!(z.checked_log(1.0), Err(ComplexError::InvalidBase));
+    /// assert_eq!(z.checked_log(-2.0), Err(ComplexError::InvalidBase));
+    /// ```
+    pub fn checked_log(&self, arb: T) -> Result<ComplexNumber<T>, ComplexError> {
+        finite(self)?;
+        if !arb.is_finite() || arb <= T::ZERO || arb == T::ONE {
+            return Err(ComplexError::InvalidBase);
+        }
+        let ln = self.checked_ln()?;
+        result(ln.unscale(arb.ln()))
+    }
+
+    /// Calculates the principal logarithm of this complex number with respect to a complex base,
+    /// failing for zero and for a base whose logarithm is zero or undefined.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - A reference to the complex base to calculate the logarithm with respect to.
+    ///
+    /// # Errors
+    ///
+    /// `NotFinite` if this number is not finite, `InvalidBase` if `base` is zero, one or not finite,
+    /// and `LogarithmOfZero` if this number is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, checked::ComplexError};
+    ///
+    /// let z = ComplexNumber::from_real(-1.0);
+    /// assert!(z.checked_logc(&ComplexNumber::from_cartesian(0.0, 1.0)).is_ok());
+    /// assert_eq!(z.checked_logc(&ComplexNumber::from_real(1.0)), Err(ComplexError::InvalidBase));
+    /// ```
+    pub fn checked_logc(&self, base: &ComplexNumber<T>) -> Result<ComplexNumber<T>, ComplexError> {
+        finite(self)?;
+        let ln_base = base.checked_ln().map_err(|_| ComplexError::InvalidBase)?;
+        if ln_base.is_zero() {
+            return Err(ComplexError::InvalidBase);
+        }
+        let ln = self.checked_ln()?;
+        result(ln.div(&ln_base))
+    }
+}
+
+/// Fails with `NotFinite` unless both parts of `z` are finite.
+fn finite<T: Float>(z: &ComplexNumber<T>) -> Result<(), ComplexError> {
+    if z.is_finite() {
+        Ok(())
+    } else {
+        Err(ComplexError::NotFinite)
+    }
+}
+
+/// Fails with `Overflow` unless both parts of the result `z` are finite.
+fn result<T: Float>(z: ComplexNumber<T>) -> Result<ComplexNumber<T>, ComplexError> {
+    if z.is_finite() {
+        Ok(z)
+    } else {
+        Err(ComplexError::Overflow)
+    }
+}
@@ -0,0 +1,139 @@
+//! `From` conversions between `ComplexNumber`, its Cartesian and polar forms, real numbers, pairs and
+//! two-element arrays.
+//!
+//! A real number converts to a complex number with a zero imaginary part, and pairs and arrays are read
+//! as `(real, imaginary)`, so functions taking `impl Into<ComplexNumber>` accept all of them:
+//!
+//! ```
+//! use complexible::complex_numbers::*;
+//!
+//! fn gain(h: impl Into<ComplexNumber>) -> f64 {
+//!     h.into().abs()
+//! }
+//!
+//! assert_eq!(gain(-2.0), 2.0);
+//! assert_eq!(gain((3.0, 4.0)), 5.0);
+//! assert_eq!(gain([0.0, 1.0]), 1.0);
+//! assert_eq!(gain(ComplexNumber::I), 1.0);
+//! ```
+
+use super::*;
+use z::*;
+
+impl<T: Float> From<T> for ComplexNumber<T> {
+    fn from(real: T) -> ComplexNumber<T> {
+        ComplexNumber::from_real(real)
+    }
+}
+
+impl<T: Float> From<(T, T)> for ComplexNumber<T> {
+    fn from((real, imaginary): (T, T)) -> ComplexNumber<T> {
+        ComplexNumber::from_cartesian(real, imaginary)
+    }
+}
+
+impl<T: Float> From<[T; 2]> for ComplexNumber<T> {
+    fn from([real, imaginary]: [T; 2]) -> ComplexNumber<T> {
+        ComplexNumber::from_cartesian(real, imaginary)
+    }
+}
+
+/// Implements the conversions from a complex number to a `(real, imaginary)` pair and array. Coherence
+/// rules forbid a blanket `impl<T> From<ComplexNumber<T>> for (T, T)`, so this is instantiated once per
+/// primitive type.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::*;
+///
+/// let z = ComplexNumber::from_cartesian(3.0, -4.0);
+/// let (re, im): (f64, f64) = z.into();
+/// assert_eq!((re, im), (3.0, -4.0));
+/// assert_eq!(<[f64; 2]>::from(z), [3.0, -4.0]);
+/// ```
+macro_rules! primitive_conversions {
+    ($t:ty) => {
+        impl From<ComplexNumber<$t>> for ($t, $t) {
+            fn from(z: ComplexNumber<$t>) -> ($t, $t) {
+                (z.real(), z.imag())
+            }
+        }
+        impl From<ComplexNumber<$t>> for [$t; 2] {
+            fn from(z: ComplexNumber<$t>) -> [$t; 2] {
+                [z.real(), z.imag()]
+            }
+        }
+    };
+}
+
+primitive_conversions!(f32);
+primitive_conversions!(f64);
+
+/// Converts between `ComplexNumber` and the `z` module's `CartesianComplexNumber` and
+/// `PolarComplexNumber`. The polar form takes the magnitude and the principal argument in radians.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::{*, z::*};
+///
+/// let z = ComplexNumber::from_cartesian(0.0, 2.0);
+/// let polar = PolarComplexNumber::from(z);
+/// assert_eq!(polar.magnitude, 2.0);
+/// assert_eq!(polar.angle.radians(), std::f64::consts::FRAC_PI_2);
+///
+/// let cartesian: CartesianComplexNumber = z.into();
+/// assert_eq!((cartesian.real, cartesian.imaginary), (0.0, 2.0));
+/// assert_eq!(ComplexNumber::from(cartesian), z);
+///
+/// let back = ComplexNumber::from(PolarComplexNumber::new(2.0, Angle::from_degrees(90.0)));
+/// assert_eq!(back, z);
+/// ```
+impl<T: Float> From<CartesianComplexNumber<T>> for ComplexNumber<T> {
+    fn from(z: CartesianComplexNumber<T>) -> ComplexNumber<T> {
+        ComplexNumber::from_cartesian(z.real, z.imaginary)
+    }
+}
+
+impl<T: Float> From<ComplexNumber<T>> for CartesianComplexNumber<T> {
+    fn from(z: ComplexNumber<T>) -> CartesianComplexNumber<T> {
+        CartesianComplexNumber::new(z.real(), z.imag())
+    }
+}
+
+impl<T: Float> From<PolarComplexNumber<T>> for ComplexNumber<T> {
+    fn from(z: PolarComplexNumber<T>) -> ComplexNumber<T> {
+        ComplexNumber::from_polar(z.magnitude, z.angle)
+    }
+}
+
+impl<T: Float> From<ComplexNumber<T>> for PolarComplexNumber<T> {
+    fn from(z: ComplexNumber<T>) -> PolarComplexNumber<T> {
+        PolarComplexNumber::new(z.abs(), z.arg())
+    }
+}
+
+/// Converts between the Cartesian and polar forms, as `to_polar` and `to_cartesian` do.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::z::*;
+///
+/// let polar: PolarComplexNumber = CartesianComplexNumber::new(3.0, 4.0).into();
+/// assert_eq!(polar.magnitude, 5.0);
+/// let cartesian = CartesianComplexNumber::from(polar);
+/// assert!((cartesian.real - 3.0).abs() < 1e-15 && (cartesian.imaginary - 4.0).abs() < 1e-15);
+/// ```
+impl<T: Float> From<CartesianComplexNumber<T>> for PolarComplexNumber<T> {
+    fn from(z: CartesianComplexNumber<T>) -> PolarComplexNumber<T> {
+        z.to_polar()
+    }
+}
+
+impl<T: Float> From<PolarComplexNumber<T>> for CartesianComplexNumber<T> {
+    fn from(z: PolarComplexNumber<T>) -> CartesianComplexNumber<T> {
+        z.to_cartesian()
+    }
+}
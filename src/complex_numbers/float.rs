@@ -0,0 +1,248 @@
+//! # `float` Module
+//!
+//! This module provides the `Float` trait, the scalar abstraction that every type in `complex_numbers`
+//! is generic over. It is implemented for `f32` and `f64`; any other type that provides the same
+//! arithmetic and elementary functions (for example a fixed-point or software float) can implement it
+//! too and be used as the component type of `ComplexNumber`, `Angle`, `Radian` and `Degree`.
+
+use std::fmt::{Debug, Display, LowerExp};
+use std::str::FromStr;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// A real floating-point scalar that can be used as the component type of the complex-number types.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::float::Float;
+///
+/// fn hypotenuse<T: Float>(a: T, b: T) -> T {
+///     (a * a + b * b).sqrt()
+/// }
+/// assert_eq!(hypotenuse(3.0_f32, 4.0), 5.0);
+/// assert_eq!(hypotenuse(3.0_f64, 4.0), 5.0);
+/// ```
+pub trait Float:
+    Copy
+    + Debug
+    + Display
+    + LowerExp
+    + PartialEq
+    + PartialOrd
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// The additive identity, `0`.
+    const ZERO: Self;
+    /// The multiplicative identity, `1`.
+    const ONE: Self;
+    /// Archimedes' constant, `π`.
+    const PI: Self;
+    /// Positive infinity, `+∞`.
+    const INFINITY: Self;
+    /// Not a number.
+    const NAN: Self;
+    /// The largest finite value.
+    const MAX: Self;
+    /// The smallest positive normal value.
+    const MIN_POSITIVE: Self;
+    /// The difference between `1` and the next larger representable value.
+    const EPSILON: Self;
+
+    /// Converts an `f64` into this type, rounding if necessary.
+    fn from_f64(value: f64) -> Self;
+    /// Converts this value into an `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Returns `true` if this value is NaN.
+    fn is_nan(self) -> bool;
+    /// Returns `true` if this value is positive or negative infinity.
+    fn is_infinite(self) -> bool;
+    /// Returns `true` if this value is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+    /// Returns the number of representable values between `self` and `other` (`0` if they are equal,
+    /// treating `+0` and `-0` as equal), or `u64::MAX` if either is NaN.
+    fn ulps_between(self, other: Self) -> u64;
+
+    /// Returns the absolute value.
+    fn abs(self) -> Self;
+    /// Returns a value with the magnitude of `self` and the sign of `sign`.
+    fn copysign(self, sign: Self) -> Self;
+    /// Returns the nearest integer, rounding half-way cases away from zero.
+    fn round(self) -> Self;
+    /// Returns the integer part, rounding towards zero.
+    fn trunc(self) -> Self;
+    /// Returns the square root.
+    fn sqrt(self) -> Self;
+    /// Raises this value to a floating-point power.
+    fn powf(self, n: Self) -> Self;
+    /// Raises this value to an integer power.
+    fn powi(self, n: i32) -> Self;
+    /// Returns `e^self`.
+    fn exp(self) -> Self;
+    /// Returns the natural logarithm.
+    fn ln(self) -> Self;
+    /// Returns `ln(1 + self)`, accurate even when `self` is close to zero.
+    fn ln_1p(self) -> Self;
+    /// Returns the base-10 logarithm.
+    fn log10(self) -> Self;
+    /// Returns the logarithm with respect to an arbitrary base.
+    fn log(self, base: Self) -> Self;
+    /// Returns the sine (in radians).
+    fn sin(self) -> Self;
+    /// Returns the cosine (in radians).
+    fn cos(self) -> Self;
+    /// Returns the tangent (in radians).
+    fn tan(self) -> Self;
+    /// Returns the hyperbolic sine.
+    fn sinh(self) -> Self;
+    /// Returns the hyperbolic cosine.
+    fn cosh(self) -> Self;
+    /// Returns the hyperbolic tangent.
+    fn tanh(self) -> Self;
+    /// Returns the inverse hyperbolic sine.
+    fn asinh(self) -> Self;
+    /// Returns the arcsine, in radians.
+    fn asin(self) -> Self;
+    /// Returns the arccosine, in radians.
+    fn acos(self) -> Self;
+    /// Returns the arctangent, in radians.
+    fn atan(self) -> Self;
+    /// Returns the four-quadrant arctangent of `self` (y) and `other` (x), in radians.
+    fn atan2(self, other: Self) -> Self;
+    /// Returns `sqrt(self² + other²)` without intermediate overflow.
+    fn hypot(self, other: Self) -> Self;
+}
+
+/// Implements `Float` for a primitive floating-point type by forwarding to its inherent methods.
+macro_rules! impl_float {
+    ($t:ident, $bits:ident) => {
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const PI: Self = std::$t::consts::PI;
+            const INFINITY: Self = $t::INFINITY;
+            const NAN: Self = $t::NAN;
+            const MAX: Self = $t::MAX;
+            const MIN_POSITIVE: Self = $t::MIN_POSITIVE;
+            const EPSILON: Self = $t::EPSILON;
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn is_nan(self) -> bool {
+                <$t>::is_nan(self)
+            }
+            fn is_infinite(self) -> bool {
+                <$t>::is_infinite(self)
+            }
+            fn is_finite(self) -> bool {
+                <$t>::is_finite(self)
+            }
+            fn ulps_between(self, other: Self) -> u64 {
+                if self.is_nan() || other.is_nan() {
+                    return u64::MAX;
+                }
+                // Map the sign-magnitude bit patterns onto a monotonic two's-complement scale.
+                let ordered = |value: $t| {
+                    let bits = value.to_bits() as $bits;
+                    if bits < 0 {
+                        $bits::MIN.wrapping_sub(bits)
+                    } else {
+                        bits
+                    }
+                };
+                (i128::from(ordered(self)) - i128::from(ordered(other))).unsigned_abs() as u64
+            }
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+            fn copysign(self, sign: Self) -> Self {
+                <$t>::copysign(self, sign)
+            }
+            fn round(self) -> Self {
+                <$t>::round(self)
+            }
+            fn trunc(self) -> Self {
+                <$t>::trunc(self)
+            }
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+            fn powf(self, n: Self) -> Self {
+                <$t>::powf(self, n)
+            }
+            fn powi(self, n: i32) -> Self {
+                <$t>::powi(self, n)
+            }
+            fn exp(self) -> Self {
+                <$t>::exp(self)
+            }
+            fn ln(self) -> Self {
+                <$t>::ln(self)
+            }
+            fn ln_1p(self) -> Self {
+                <$t>::ln_1p(self)
+            }
+            fn log10(self) -> Self {
+                <$t>::log10(self)
+            }
+            fn log(self, base: Self) -> Self {
+                <$t>::log(self, base)
+            }
+            fn sin(self) -> Self {
+                <$t>::sin(self)
+            }
+            fn cos(self) -> Self {
+                <$t>::cos(self)
+            }
+            fn tan(self) -> Self {
+                <$t>::tan(self)
+            }
+            fn sinh(self) -> Self {
+                <$t>::sinh(self)
+            }
+            fn cosh(self) -> Self {
+                <$t>::cosh(self)
+            }
+            fn tanh(self) -> Self {
+                <$t>::tanh(self)
+            }
+            fn asinh(self) -> Self {
+                <$t>::asinh(self)
+            }
+            fn asin(self) -> Self {
+                <$t>::asin(self)
+            }
+            fn acos(self) -> Self {
+                <$t>::acos(self)
+            }
+            fn atan(self) -> Self {
+                <$t>::atan(self)
+            }
+            fn atan2(self, other: Self) -> Self {
+                <$t>::atan2(self, other)
+            }
+            fn hypot(self, other: Self) -> Self {
+                <$t>::hypot(self, other)
+            }
+        }
+    };
+}
+
+impl_float!(f32, i32);
+impl_float!(f64, i64);
@@ -0,0 +1,339 @@
+//! # `format` Module
+//!
+//! This module provides the `Display` and `LowerExp` implementations of `ComplexNumber` and the
+//! `ComplexFormat` builder behind them. The standard format flags are honoured:
+//!
+//! * `{}` prints the Cartesian form, `3 + 4j`, with the sign of the imaginary part as the operator.
+//! * `{:.N}` prints every number with `N` decimal places.
+//! * `{:e}` prints every number in scientific notation.
+//! * `{:+}` always prints the sign of the leading number.
+//! * `{:#}` prints the polar form in degrees, `5∠53.13°`.
+//! * A width (`{:>20}`, `{:*^24}`) pads the whole value, which keeps columns aligned in tables.
+//!
+//! ```
+//! use complexible::complex_numbers::*;
+//!
+//! let z = ComplexNumber::from_cartesian(3.0, -4.0);
+//! assert_eq!(format!("{}", z), "3 - 4j");
+//! assert_eq!(format!("{:.2}", z), "3.00 - 4.00j");
+//! assert_eq!(format!("{:+}", z), "+3 - 4j");
+//! assert_eq!(format!("{:e}", z), "3e0 - 4e0j");
+//! assert_eq!(format!("{:#.2}", z), "5.00∠-53.13°");
+//! assert_eq!(format!("[{:>10}]", z), "[    3 - 4j]");
+//! ```
+//!
+//! For anything else, build a `ComplexFormat` and pass it to `ComplexNumber::format_with`.
+
+use super::*;
+
+/// The notation a complex number is written in.
+///
+/// # Variants
+///
+/// * `Cartesian` - Real and imaginary parts, `3 + 4j`.
+/// * `PolarRadians` - Magnitude and angle in radians, `5∠0.927rad`.
+/// * `PolarDegrees` - Magnitude and angle in degrees, `5∠53.13°`.
+/// * `Exponential` - Magnitude and angle in radians in Euler's form, `5e^(j0.927)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Form {
+    Cartesian,
+    PolarRadians,
+    PolarDegrees,
+    Exponential,
+}
+
+/// The symbol used for the imaginary unit.
+///
+/// # Variants
+///
+/// * `I` - The mathematician's `i`.
+/// * `J` - The engineer's `j`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImaginaryUnit {
+    I,
+    J,
+}
+impl ImaginaryUnit {
+    /// Returns the unit as a character.
+    pub fn symbol(&self) -> char {
+        match self {
+            ImaginaryUnit::I => 'i',
+            ImaginaryUnit::J => 'j',
+        }
+    }
+}
+
+/// The spacing around the operator between the two parts of a complex number.
+///
+/// # Variants
+///
+/// * `Spaced` - A space on each side, `3 + 4j` or `5 ∠ 53.13°`.
+/// * `Compact` - No spaces, `3+4j` or `5∠53.13°`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separator {
+    Spaced,
+    Compact,
+}
+
+/// Options for writing a complex number as text, built with chained setters.
+///
+/// The default is the Cartesian form with a `j` unit, spaced separators and shortest round-trip
+/// precision, which is what `{}` prints.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::{*, format::*};
+///
+/// let z = ComplexNumber::from_cartesian(3.0, 4.0);
+/// let format = ComplexFormat::new()
+///     .form(Form::PolarDegrees)
+///     .separator(Separator::Compact)
+///     .precision(2);
+/// assert_eq!(z.format_with(format).to_string(), "5.00∠53.13°");
+///
+/// let format = ComplexFormat::new().unit(ImaginaryUnit::I).separator(Separator::Compact);
+/// assert_eq!(z.format_with(format).to_string(), "3+4i");
+///
+/// let format = ComplexFormat::new().form(Form::Exponential).precision(3);
+/// assert_eq!(z.format_with(format).to_string(), "5.000e^(j0.927)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComplexFormat {
+    form: Form,
+    unit: ImaginaryUnit,
+    separator: Separator,
+    precision: Option<usize>,
+    scientific: bool,
+    sign_plus: bool,
+}
+impl Default for ComplexFormat {
+    fn default() -> ComplexFormat {
+        ComplexFormat::new()
+    }
+}
+impl ComplexFormat {
+    /// Creates the default format: Cartesian form, `j` unit, spaced separators, shortest precision.
+    pub fn new() -> ComplexFormat {
+        ComplexFormat {
+            form: Form::Cartesian,
+            unit: ImaginaryUnit::J,
+            separator: Separator::Spaced,
+            precision: None,
+            scientific: false,
+            sign_plus: false,
+        }
+    }
+
+    /// Sets the notation.
+    pub fn form(mut self, form: Form) -> ComplexFormat {
+        self.form = form;
+        self
+    }
+
+    /// Sets the symbol of the imaginary unit.
+    pub fn unit(mut self, unit: ImaginaryUnit) -> ComplexFormat {
+        self.unit = unit;
+        self
+    }
+
+    /// Sets the spacing around the operator.
+    pub fn separator(mut self, separator: Separator) -> ComplexFormat {
+        self.separator = separator;
+        self
+    }
+
+    /// Prints every number with exactly `digits` decimal places.
+    pub fn precision(mut self, digits: usize) -> ComplexFormat {
+        self.precision = Some(digits);
+        self
+    }
+
+    /// Prints every number in scientific notation (`1.5e3`) when `scientific` is `true`.
+    pub fn scientific(mut self, scientific: bool) -> ComplexFormat {
+        self.scientific = scientific;
+        self
+    }
+
+    /// Always prints the sign of the leading number when `sign_plus` is `true`.
+    pub fn sign_plus(mut self, sign_plus: bool) -> ComplexFormat {
+        self.sign_plus = sign_plus;
+        self
+    }
+
+    /// Returns the notation.
+    pub fn get_form(&self) -> Form {
+        self.form
+    }
+
+    /// Returns the symbol of the imaginary unit.
+    pub fn get_unit(&self) -> ImaginaryUnit {
+        self.unit
+    }
+
+    /// Returns the spacing around the operator.
+    pub fn get_separator(&self) -> Separator {
+        self.separator
+    }
+
+    /// Returns the number of decimal places, if fixed.
+    pub fn get_precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Writes a real number with this format's precision and notation. The sign is only forced
+    /// when `sign_plus` is set on the format and `leading` is `true`.
+    pub fn number<T: Float>(&self, value: T, leading: bool) -> String {
+        let plus = self.sign_plus && leading;
+        match (self.precision, self.scientific, plus) {
+            (Some(p), false, false) => format!("{:.*}", p, value),
+            (Some(p), false, true) => format!("{:+.*}", p, value),
+            (Some(p), true, false) => format!("{:.*e}", p, value),
+            (Some(p), true, true) => format!("{:+.*e}", p, value),
+            (None, false, false) => format!("{}", value),
+            (None, false, true) => format!("{:+}", value),
+            (None, true, false) => format!("{:e}", value),
+            (None, true, true) => format!("{:+e}", value),
+        }
+    }
+
+    /// Writes a complex number with this format.
+    fn write<T: Float>(&self, z: &ComplexNumber<T>) -> String {
+        let gap = match self.separator {
+            Separator::Spaced => " ",
+            Separator::Compact => "",
+        };
+        let unit = self.unit.symbol();
+        match self.form {
+            Form::Cartesian => {
+                let (operator, imaginary) = split_sign(z.imag());
+                format!(
+                    "{}{}{}{}{}{}",
+                    self.number(z.real(), true),
+                    gap,
+                    operator,
+                    gap,
+                    self.number(imaginary, false),
+                    unit
+                )
+            }
+            Form::PolarRadians | Form::PolarDegrees => {
+                let magnitude = z.real().hypot(z.imag());
+                let angle = Angle::from_radians(z.imag().atan2(z.real()));
+                let angle = match self.form {
+                    Form::PolarDegrees => format!("{}°", self.number(angle.degrees(), false)),
+                    _ => format!("{}{}rad", self.number(angle.radians(), false), gap),
+                };
+                format!("{}{}∠{}{}", self.number(magnitude, true), gap, gap, angle)
+            }
+            Form::Exponential => {
+                let magnitude = z.real().hypot(z.imag());
+                let (sign, angle) = split_sign(z.imag().atan2(z.real()));
+                let sign = if sign == '-' { "-" } else { "" };
+                format!(
+                    "{}e^({}{}{})",
+                    self.number(magnitude, true),
+                    sign,
+                    unit,
+                    self.number(angle, false)
+                )
+            }
+        }
+    }
+}
+
+/// Splits a value into the operator that should precede it (`+` or `-`, following the sign bit so
+/// that `-0` prints as `- 0`) and its absolute value.
+pub(super) fn split_sign<T: Float>(value: T) -> (char, T) {
+    if T::ONE.copysign(value) < T::ZERO {
+        ('-', -value)
+    } else {
+        ('+', value)
+    }
+}
+
+/// A complex number paired with a `ComplexFormat`, returned by `ComplexNumber::format_with`.
+/// It implements `Display`, so it can be used with `format!`, `println!` and `to_string`.
+pub struct FormattedComplex<'a, T: Float> {
+    z: &'a ComplexNumber<T>,
+    format: ComplexFormat,
+}
+impl<T: Float> fmt::Display for FormattedComplex<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad(f, &self.format.write(self.z))
+    }
+}
+
+impl<T: Float> ComplexNumber<T> {
+    /// Pairs this complex number with a format, for use with `format!`, `println!` or `to_string`.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The `ComplexFormat` to write the number with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, format::*};
+    ///
+    /// let z = ComplexNumber::from_cartesian(1.0, 1.0);
+    /// let format = ComplexFormat::new().form(Form::PolarRadians).precision(4);
+    /// assert_eq!(format!("{}", z.format_with(format)), "1.4142 ∠ 0.7854 rad");
+    /// ```
+    pub fn format_with(&self, format: ComplexFormat) -> FormattedComplex<'_, T> {
+        FormattedComplex { z: self, format }
+    }
+}
+
+/// Builds a `ComplexFormat` from the flags of a standard formatter.
+fn from_flags(f: &fmt::Formatter, scientific: bool) -> ComplexFormat {
+    let mut format = ComplexFormat::new()
+        .scientific(scientific)
+        .sign_plus(f.sign_plus());
+    if let Some(precision) = f.precision() {
+        format = format.precision(precision);
+    }
+    if f.alternate() {
+        format = format
+            .form(Form::PolarDegrees)
+            .separator(Separator::Compact);
+    }
+    format
+}
+
+/// Writes `s` honouring the width, fill and alignment of the formatter (right-aligned by default,
+/// like numbers). Unlike `Formatter::pad`, the precision is not treated as a maximum length.
+fn pad(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    let length = s.chars().count();
+    let width = match f.width() {
+        Some(width) if width > length => width,
+        _ => return f.write_str(s),
+    };
+    let padding = width - length;
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        write!(f, "{}", fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        write!(f, "{}", fill)?;
+    }
+    Ok(())
+}
+
+impl<T: Float> fmt::Display for ComplexNumber<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad(f, &from_flags(f, false).write(self))
+    }
+}
+
+impl<T: Float> fmt::LowerExp for ComplexNumber<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad(f, &from_flags(f, true).write(self))
+    }
+}
@@ -6,7 +6,12 @@
 //! a convenient way to represent, convert, and manipulate angle values in both radians and degrees.
 //!
 //! The module includes methods for creating angle values from radians and degrees, converting
-//! between radians and degrees, and performing arithmetic operations on angles.
+//! between radians and degrees, reading and storing an `Angle` in gradians, turns, milliradians, arcminutes
+//! and arcseconds (`Angle::new`, `Angle::in_unit`, `Angle::to_unit`), writing it in degrees, minutes and seconds
+//! (`Dms`, `12°34'56.7"`), and performing arithmetic operations on angles: `+`, `-`, scaling, comparison,
+//! `sin`/`cos`/`tan`, and the inverse constructors `Angle::asin`, `Angle::acos`, `Angle::atan` and `Angle::atan2`.
+//! For frequency sweeps it provides phase unwrapping (`unwrap_radians`, `unwrap_angles`, `unwrap_radians_2d`) and
+//! `group_delay`.
 //!
 //! Embrace the flexibility and precision offered by the angle module for your angle-related calculations!
 //!
@@ -22,26 +27,90 @@
 //! The z module allows you to seamlessly work with complex numbers in both Cartesian and polar coordinates
 //! and easily convert between the two representations. Take advantage of the z module to harness the full
 //! power and flexibility of complex number representations in your calculations!
+//!
+//! `PolarComplexNumber` implements `*`, `/`, `pow`, `inv` and `conj` directly on its magnitude and angle,
+//! wrapping the angle into the principal range, so long gain/phase cascades never leave polar form.
+//! Only `+` and `-` go through Cartesian form.
+//!
+//! `From` converts between `ComplexNumber`, `CartesianComplexNumber` and `PolarComplexNumber`, and builds a
+//! `ComplexNumber` from a real number, a `(re, im)` pair or a `[re, im]` array, so functions can take
+//! `impl Into<ComplexNumber>`. A `ComplexNumber` converts back into a pair or an array with `into()`.
+//!
+//! # `format` Module
+//!
+//! This module provides the `Display` implementation of `ComplexNumber`, which prints `3 - 4j` and honours
+//! the `{:.N}`, `{:e}`, `{:+}` and `{:#}` flags, and the `ComplexFormat` builder for choosing the form,
+//! the imaginary unit and the separator style explicitly. The same options drive the `to_latex`, `to_mathml` and
+//! `to_unicode` renderers on `ComplexNumber` and `Angle`, for typesetting values in reports.
+//!
+//! # `serialization` Module
+//!
+//! With the `serde` cargo feature, this module implements `Serialize` and `Deserialize` for complex numbers
+//! and angles, and provides the `cartesian`, `tuple` and `polar` layouts for `#[serde(with = ...)]`.
+//!
+//! # `checked` Module
+//!
+//! This module provides the `is_nan`, `is_finite`, `is_infinite` and `is_zero` predicates, and checked variants
+//! of division, powers, roots and logarithms (`checked_div`, `checked_ln`, `checked_log`, `checked_nth_root`, …)
+//! that return a `ComplexError` instead of silently producing infinities or NaNs, so that control loops can
+//! detect degenerate inputs rather than propagate them.
+//!
+//! # `summation` Module
+//!
+//! `ComplexNumber` implements `Sum` and `Product`, by value and by reference, so iterators of complex numbers
+//! can be added up with `.sum()` and multiplied with `.product()`. For long sequences, such as thousands of phasor
+//! contributions, this module provides the `CompensatedSum` accumulator (Kahan–Babuška–Neumaier), which can be
+//! collected into from an iterator, and `ComplexNumber::sum_compensated`.
+//!
+//! # `float` Module
+//!
+//! This module provides the `Float` trait that all of the types above are generic over. It is implemented
+//! for `f32` and `f64`, and every type defaults to `f64`, so `ComplexNumber` on its own means `ComplexNumber<f64>`.
+//! Use `f32` components (or the `Complex32` and `Angle32` aliases) to halve the memory footprint of large buffers:
+//!
+//! ```
+//! use complexible::complex_numbers::*;
+//!
+//! let a: Complex32 = ComplexNumber::from_cartesian(3.0_f32, 4.0);
+//! let b = ComplexNumber::from_cartesian(1.0_f32, -1.0);
+//! let sum = &a + &b;
+//! assert_eq!(sum.real(), 4.0_f32);
+//! assert_eq!(sum.imag(), 3.0_f32);
+//! ```
 
 pub mod angle;
+pub mod approx;
+pub mod checked;
+mod convert;
+mod division;
+pub mod float;
+pub mod format;
+mod ops;
+pub mod parse;
+mod render;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod summation;
+mod trig;
 pub mod z;
 
 use angle::*;
+use float::Float;
 use std::fmt;
-use z::*;
 
-/// Represents an angle, stored in both degrees and radians.
+/// Represents an angle, stored as a single value in the unit it was created with. Conversion to the
+/// other unit happens on demand, so an angle created from degrees reads back the exact same degrees.
 ///
 /// # Fields
 ///
-/// * `d` - The angle value in degrees, represented as a `Degree` struct.
-/// * `r` - The angle value in radians, represented as a `Radian` struct.
-#[derive(Debug)]
-pub struct Angle {
-    pub d: Degree,
-    pub r: Radian,
+/// * `value` - The value of the angle in `unit`.
+/// * `unit` - The `AngleUnit` that `value` is expressed in.
+#[derive(Debug, Clone, Copy)]
+pub struct Angle<T = f64> {
+    value: T,
+    unit: AngleUnit,
 }
-impl Angle {
+impl<T: Float> Angle<T> {
     /// Creates a new `Angle` value from a given angle value in degrees.
     ///
     /// # Arguments
@@ -54,10 +123,8 @@ impl Angle {
     /// use complexible::complex_numbers::*;
     /// let angle = Angle::from_degrees(45.0);
     /// ```
-    pub fn from_degrees(d: f64) -> Angle {
-        let d = Degree::from(d);
-        let r = d.to_radians();
-        Angle { d, r }
+    pub fn from_degrees(d: T) -> Angle<T> {
+        Angle { value: d, unit: AngleUnit::Degrees }
     }
 
     /// Creates a new `Angle` value from a given angle value in radians.
@@ -72,31 +139,182 @@ impl Angle {
     /// use complexible::complex_numbers::*;
     /// let angle = Angle::from_radians(std::f64::consts::PI);
     /// ```
-    pub fn from_radians(r: f64) -> Angle {
-        let r = Radian::from(r);
-        let d = r.to_degrees();
-        Angle { d, r }
+    pub fn from_radians(r: T) -> Angle<T> {
+        Angle { value: r, unit: AngleUnit::Radians }
     }
-}
-impl Clone for Angle {
-    fn clone(&self) -> Angle {
-        Angle::from_degrees(self.d.value)
+
+    /// Creates a new `Angle` value from a given angle value in any unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value of the angle in `unit`.
+    /// * `unit` - The `AngleUnit` that `value` is expressed in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, angle::AngleUnit};
+    ///
+    /// let angle = Angle::new(100.0, AngleUnit::Gradians);
+    /// assert_eq!(angle.value(), 100.0);
+    /// assert_eq!(angle.unit(), AngleUnit::Gradians);
+    /// ```
+    pub fn new(value: T, unit: AngleUnit) -> Angle<T> {
+        Angle { value, unit }
+    }
+
+    /// Returns the value of the angle in radians.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let angle = Angle::from_degrees(180.0);
+    /// assert_eq!(angle.radians(), std::f64::consts::PI);
+    /// ```
+    pub fn radians(&self) -> T {
+        self.in_unit(AngleUnit::Radians)
+    }
+
+    /// Returns the value of the angle in degrees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(Angle::from_degrees(30.0).degrees(), 30.0);
+    /// assert_eq!(Angle::from_radians(std::f64::consts::PI).degrees(), 180.0);
+    /// ```
+    pub fn degrees(&self) -> T {
+        self.in_unit(AngleUnit::Degrees)
+    }
+
+    /// Returns the value of the angle in any unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - The `AngleUnit` to read the angle in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, angle::AngleUnit};
+    ///
+    /// let angle = Angle::from_degrees(90.0);
+    /// assert_eq!(angle.in_unit(AngleUnit::Turns), 0.25);
+    /// assert_eq!(angle.in_unit(AngleUnit::Gradians), 100.0);
+    /// assert_eq!(angle.in_unit(AngleUnit::Arcseconds), 324000.0);
+    /// ```
+    pub fn in_unit(&self, unit: AngleUnit) -> T {
+        convert_angle(self.value, self.unit, unit)
+    }
+
+    /// Returns the same angle, stored in another unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - The `AngleUnit` to store the angle in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, angle::AngleUnit};
+    ///
+    /// let angle = Angle::from_radians(std::f64::consts::PI).to_unit(AngleUnit::Milliradians);
+    /// assert_eq!(angle.unit(), AngleUnit::Milliradians);
+    /// assert_eq!(angle.value(), 1000.0 * std::f64::consts::PI);
+    /// ```
+    pub fn to_unit(&self, unit: AngleUnit) -> Angle<T> {
+        Angle { value: self.in_unit(unit), unit }
+    }
+
+    /// Returns the value of the angle in the unit it was created with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, angle::AngleUnit};
+    ///
+    /// let angle = Angle::from_degrees(90.0);
+    /// assert_eq!(angle.value(), 90.0);
+    /// assert_eq!(angle.unit(), AngleUnit::Degrees);
+    /// ```
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the unit the angle was created with.
+    pub fn unit(&self) -> AngleUnit {
+        self.unit
     }
 }
+/// An `Angle` with `f32` values.
+pub type Angle32 = Angle<f32>;
+/// An `Angle` with `f64` values.
+pub type Angle64 = Angle<f64>;
 
-/// Represents a complex number in both Cartesian and polar form.
+/// Represents a complex number, stored in Cartesian form. The magnitude and angle are computed on demand.
 ///
 /// # Fields
 ///
-/// * `cartesian` - The complex number in Cartesian form, represented as a `CartesianComplexNumber` struct.
-/// * `polar` - The complex number in polar form, represented as a `PolarComplexNumber` struct.
-#[derive(Debug)]
-pub struct ComplexNumber {
-    cartesian: CartesianComplexNumber,
-    polar: PolarComplexNumber,
+/// * `re` - The real part of the complex number.
+/// * `im` - The imaginary part of the complex number.
+///
+/// # Operators
+///
+/// `+`, `-`, `*`, `/` (and their `+=`, `-=`, `*=`, `/=` forms) and unary `-` are implemented for
+/// owned values, references and `f64` operands on either side.
+///
+/// ```
+/// use complexible::complex_numbers::*;
+///
+/// let z = ComplexNumber::from_cartesian(3.0, 4.0);
+/// let w = 2.0 * &z - 1.0;
+/// assert_eq!(w, ComplexNumber::from_cartesian(5.0, 8.0));
+///
+/// let mut acc = -&z;
+/// acc += &w;
+/// acc *= 2.0;
+/// assert_eq!(acc, ComplexNumber::from_cartesian(4.0, 8.0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ComplexNumber<T = f64> {
+    re: T,
+    im: T,
 }
 
-impl ComplexNumber {
+/// A `ComplexNumber` with `f32` components.
+pub type Complex32 = ComplexNumber<f32>;
+/// A `ComplexNumber` with `f64` components.
+pub type Complex64 = ComplexNumber<f64>;
+
+impl<T: Float> ComplexNumber<T> {
+    /// The imaginary unit, `i`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// const J: Complex64 = ComplexNumber::I;
+    /// assert_eq!(J * J, ComplexNumber::from_real(-1.0));
+    /// ```
+    pub const I: ComplexNumber<T> = ComplexNumber { re: T::ZERO, im: T::ONE };
+
+    /// The multiplicative identity, `1`.
+    pub const ONE: ComplexNumber<T> = ComplexNumber { re: T::ONE, im: T::ZERO };
+
+    /// The additive identity, `0`.
+    pub const ZERO: ComplexNumber<T> = ComplexNumber { re: T::ZERO, im: T::ZERO };
+
+    /// A complex number whose parts are both NaN.
+    pub const NAN: ComplexNumber<T> = ComplexNumber { re: T::NAN, im: T::NAN };
+
+    /// Positive real infinity, `+∞ + 0i`.
+    pub const INFINITY: ComplexNumber<T> = ComplexNumber { re: T::INFINITY, im: T::ZERO };
+
     /// Creates a new `ComplexNumber` from its Cartesian coordinates.
     ///
     /// # Arguments
@@ -109,11 +327,11 @@ impl ComplexNumber {
     /// ```  
     /// use complexible::complex_numbers::*;
     /// let complex = ComplexNumber::from_cartesian(1.0, 1.0); //1 + 1 J
+    ///
+    /// static OFFSET: Complex64 = ComplexNumber::from_cartesian(0.5, -0.5);
     /// ```
-    pub fn from_cartesian(real: f64, imaginary: f64) -> ComplexNumber {
-        let cartesian = CartesianComplexNumber { real, imaginary };
-        let polar = cartesian.to_polar();
-        ComplexNumber { cartesian, polar }
+    pub const fn from_cartesian(real: T, imaginary: T) -> ComplexNumber<T> {
+        ComplexNumber { re: real, im: imaginary }
     }
 
     /// Creates a new `ComplexNumber` from its polar coordinates.
@@ -131,10 +349,9 @@ impl ComplexNumber {
     /// let angle = Angle::from_degrees(45.0);
     /// let complex = ComplexNumber::from_polar(1.0, angle);
     /// ```
-    pub fn from_polar(magnitude: f64, angle: Angle) -> ComplexNumber {
-        let polar = PolarComplexNumber { magnitude, angle };
-        let cartesian = polar.to_cartesian();
-        ComplexNumber { cartesian, polar }
+    pub fn from_polar(magnitude: T, angle: Angle<T>) -> ComplexNumber<T> {
+        let (sin, cos) = angle.sin_cos();
+        ComplexNumber::from_cartesian(magnitude * cos, magnitude * sin)
     }
 
     /// Creates a new `ComplexNumber` from a real number.
@@ -150,15 +367,94 @@ impl ComplexNumber {
     ///
     /// let complex = ComplexNumber::from_real(1.0);
     /// ```
-    pub fn from_real(real: f64) -> ComplexNumber {
-        ComplexNumber::from_cartesian(real, 0.0)
+    pub const fn from_real(real: T) -> ComplexNumber<T> {
+        ComplexNumber::from_cartesian(real, T::ZERO)
+    }
+
+    /// Creates a new `ComplexNumber` from an imaginary number.
+    ///
+    /// # Arguments
+    ///
+    /// * `imaginary` - The imaginary part of the complex number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// const TWO_J: Complex64 = ComplexNumber::from_imag(2.0);
+    /// assert_eq!(TWO_J, ComplexNumber::from_cartesian(0.0, 2.0));
+    /// ```
+    pub const fn from_imag(imaginary: T) -> ComplexNumber<T> {
+        ComplexNumber::from_cartesian(T::ZERO, imaginary)
+    }
+
+    /// Creates a new `ComplexNumber` from its magnitude and its angle in degrees. Multiples of 90° give
+    /// exact results.
+    ///
+    /// # Arguments
+    ///
+    /// * `magnitude` - The magnitude (or absolute value) of the complex number.
+    /// * `degrees` - The angle of the complex number, in degrees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let complex = ComplexNumber::from_polar_degrees(2.0, 90.0);
+    /// assert_eq!(complex, ComplexNumber::from_cartesian(0.0, 2.0));
+    /// ```
+    pub fn from_polar_degrees(magnitude: T, degrees: T) -> ComplexNumber<T> {
+        ComplexNumber::from_polar(magnitude, Angle::from_degrees(degrees))
     }
 
-    /// Returns the absolute value (or magnitude) of the complex number.
+    /// Returns `cos θ + i·sin θ`, the point at angle `θ` on the unit circle (`e^(iθ)`).
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle `θ`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// assert_eq!(ComplexNumber::cis(Angle::from_degrees(180.0)), ComplexNumber::from_real(-1.0));
+    /// let z = ComplexNumber::cis(Angle::from_radians(0.5_f64));
+    /// assert_eq!((z.real(), z.imag()), (0.5_f64.cos(), 0.5_f64.sin()));
+    /// ```
+    pub fn cis(angle: Angle<T>) -> ComplexNumber<T> {
+        let (sin, cos) = angle.sin_cos();
+        ComplexNumber::from_cartesian(cos, sin)
+    }
+
+    /// Returns the unit phasor at the given angle. This is the same as `cis`.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle of the phasor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let phasor = ComplexNumber::unit(Angle::from_degrees(-90.0));
+    /// assert_eq!(phasor, -ComplexNumber::I);
+    /// assert_eq!(phasor.abs(), 1.0);
+    /// ```
+    pub fn unit(angle: Angle<T>) -> ComplexNumber<T> {
+        ComplexNumber::cis(angle)
+    }
+
+    /// Returns the absolute value (or magnitude) of the complex number. When the sum of the squares
+    /// would overflow or underflow, the parts are scaled before squaring (`hypot`), so the result is
+    /// only infinite or zero when the magnitude itself is.
     ///
     /// # Returns
     ///
-    /// The absolute value (or magnitude) of the complex number as a `f64`.
+    /// The absolute value (or magnitude) of the complex number as a `T`.
     ///
     /// # Example
     ///
@@ -167,16 +463,43 @@ impl ComplexNumber {
     ///
     /// let complex = ComplexNumber::from_cartesian(3.0, 4.0);
     /// assert_eq!(complex.abs(), 5.0);
+    ///
+    /// let large = ComplexNumber::from_cartesian(1e200, 1e200);
+    /// assert_eq!(large.abs(), 1e200 * std::f64::consts::SQRT_2);
+    /// assert_eq!(ComplexNumber::from_cartesian(3e-200, 4e-200).abs(), 5e-200);
     /// ```
-    pub fn abs(&self) -> f64 {
-        self.polar.magnitude
+    pub fn abs(&self) -> T {
+        let squared = self.norm_sqr();
+        if squared.is_finite() && squared >= T::MIN_POSITIVE {
+            squared.sqrt()
+        } else {
+            self.re.hypot(self.im)
+        }
     }
 
-    /// Returns the angle (in radians) of the complex number.
+    /// Returns the angle (in radians) of the complex number, at full precision.
     ///
     /// # Returns
     ///
-    /// The angle (in radians) of the complex number as a `f64`.
+    /// The angle (in radians) of the complex number as a `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let complex = ComplexNumber::from_cartesian(1.0, 1.0);
+    /// assert_eq!(complex.angle_in_rads(), std::f64::consts::FRAC_PI_4);
+    /// ```
+    pub fn angle_in_rads(&self) -> T {
+        self.im.atan2(self.re)
+    }
+
+    /// Returns the angle (in radians) of the complex number, rounded to the given number of decimal places.
+    ///
+    /// # Arguments
+    ///
+    /// * `decimals` - The number of decimal places to keep.
     ///
     /// # Example
     ///
@@ -184,17 +507,17 @@ impl ComplexNumber {
     /// use complexible::complex_numbers::*;
     ///
     /// let complex = ComplexNumber::from_cartesian(1.0, 1.0);
-    /// assert_eq!(complex.angle_in_rads(), 0.7854);
+    /// assert_eq!(complex.angle_in_rads_rounded(4), 0.7854);
     /// ```
-    pub fn angle_in_rads(&self) -> f64 {
-        round_five_zeros(self.polar.angle.r.value)
+    pub fn angle_in_rads_rounded(&self, decimals: u32) -> T {
+        round_decimals(self.angle_in_rads(), decimals)
     }
 
-    /// Returns the angle (in degrees) of the complex number.
+    /// Returns the angle (in degrees) of the complex number, at full precision.
     ///
     /// # Returns
     ///
-    /// The angle (in degrees) of the complex number as a `f64`.
+    /// The angle (in degrees) of the complex number as a `T`.
     ///
     /// # Example
     ///
@@ -204,8 +527,54 @@ impl ComplexNumber {
     /// let complex = ComplexNumber::from_cartesian(1.0, 1.0);
     /// assert_eq!(complex.angle_in_degs(), 45.0);
     /// ```
-    pub fn angle_in_degs(&self) -> f64 {
-        round_three_zeros(self.polar.angle.d.value)
+    pub fn angle_in_degs(&self) -> T {
+        radianto_degrees(self.angle_in_rads()).value
+    }
+
+    /// Returns the angle (in degrees) of the complex number, rounded to the given number of decimal places.
+    ///
+    /// # Arguments
+    ///
+    /// * `decimals` - The number of decimal places to keep.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let complex = ComplexNumber::from_cartesian(1.0, 2.0);
+    /// assert_eq!(complex.angle_in_degs_rounded(3), 63.435);
+    /// ```
+    pub fn angle_in_degs_rounded(&self, decimals: u32) -> T {
+        round_decimals(self.angle_in_degs(), decimals)
+    }
+
+    /// Returns a copy of this complex number with its real and imaginary parts rounded to the given
+    /// number of decimal places. Useful for presenting results; arithmetic never rounds implicitly.
+    ///
+    /// # Arguments
+    ///
+    /// * `decimals` - The number of decimal places to keep.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let complex = ComplexNumber::from_cartesian(1.0 / 3.0, 2.0 / 3.0);
+    /// let rounded = complex.rounded(2);
+    /// assert_eq!(rounded.real(), 0.33);
+    /// assert_eq!(rounded.imag(), 0.67);
+    ///
+    /// // Values with no digits left to round are returned unchanged.
+    /// assert_eq!(ComplexNumber::from_real(1e300).rounded(10).real(), 1e300);
+    /// assert_eq!(ComplexNumber::from_real(1.5).rounded(u32::MAX).real(), 1.5);
+    /// assert_eq!(ComplexNumber::from_real(1e30_f32).rounded(9).real(), 1e30);
+    /// ```
+    pub fn rounded(&self, decimals: u32) -> ComplexNumber<T> {
+        let real = round_decimals(self.real(), decimals);
+        let imaginary = round_decimals(self.imag(), decimals);
+        ComplexNumber::from_cartesian(real, imaginary)
     }
 
     /// Returns the angle of the complex number as an `Angle` struct.
@@ -221,17 +590,17 @@ impl ComplexNumber {
     ///
     /// let complex = ComplexNumber::from_cartesian(1.0, 1.0);
     /// let angle = complex.angle_in_angle();
-    /// assert_eq!(angle.d.value, 45.0);
+    /// assert_eq!(angle.degrees(), 45.0);
     /// ```
-    pub fn angle_in_angle(&self) -> Angle {
-        self.polar.angle.clone()
+    pub fn angle_in_angle(&self) -> Angle<T> {
+        Angle::from_radians(self.angle_in_rads())
     }
 
     /// Returns the real part of the complex number.
     ///
     /// # Returns
     ///
-    /// The real part of the complex number as a `f64`.
+    /// The real part of the complex number as a `T`.
     ///
     /// # Example
     ///
@@ -241,15 +610,15 @@ impl ComplexNumber {
     /// let complex = ComplexNumber::from_cartesian(3.0, 4.0);
     /// assert_eq!(complex.real(), 3.0);
     /// ```
-    pub fn real(&self) -> f64 {
-        self.cartesian.real
+    pub fn real(&self) -> T {
+        self.re
     }
 
     /// Returns the imaginary part of the complex number.
     ///
     /// # Returns
     ///
-    /// The imaginary part of the complex number as a `f64`.
+    /// The imaginary part of the complex number as a `T`.
     ///
     /// # Example
     ///
@@ -259,8 +628,182 @@ impl ComplexNumber {
     /// let complex = ComplexNumber::from_cartesian(3.0, 4.0);
     /// assert_eq!(complex.imag(), 4.0);
     /// ```
-    pub fn imag(&self) -> f64 {
-        self.cartesian.imaginary
+    pub fn imag(&self) -> T {
+        self.im
+    }
+
+    /// Returns the complex conjugate, `re - i·im`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let complex = ComplexNumber::from_cartesian(3.0, 4.0);
+    /// assert_eq!(complex.conj(), ComplexNumber::from_cartesian(3.0, -4.0));
+    /// ```
+    pub fn conj(&self) -> ComplexNumber<T> {
+        ComplexNumber::from_cartesian(self.re, -self.im)
+    }
+
+    /// Returns the reciprocal, `1 / z`, with the same overflow- and underflow-safe algorithm as `div`.
+    /// The reciprocal of zero is an infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let complex = ComplexNumber::from_cartesian(0.0, 2.0);
+    /// assert_eq!(complex.inv(), ComplexNumber::from_cartesian(0.0, -0.5));
+    ///
+    /// let large = ComplexNumber::from_cartesian(1e300, 1e300).inv();
+    /// assert_eq!(large, ComplexNumber::from_cartesian(5e-301, -5e-301));
+    /// assert!(ComplexNumber::from_real(0.0).inv().is_infinite());
+    /// ```
+    pub fn inv(&self) -> ComplexNumber<T> {
+        let (re, im) = division::divide(T::ONE, T::ZERO, self.re, self.im);
+        ComplexNumber::from_cartesian(re, im)
+    }
+
+    /// Returns the reciprocal, `1 / z`. This is the same as `inv`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let complex = ComplexNumber::from_cartesian(3.0, 4.0);
+    /// assert_eq!(complex.recip(), complex.inv());
+    /// ```
+    pub fn recip(&self) -> ComplexNumber<T> {
+        self.inv()
+    }
+
+    /// Returns the squared magnitude, `re² + im²`, without taking a square root.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let complex = ComplexNumber::from_cartesian(3.0, 4.0);
+    /// assert_eq!(complex.norm_sqr(), 25.0);
+    /// ```
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Returns the L1 (Manhattan) norm, `|re| + |im|`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let complex = ComplexNumber::from_cartesian(3.0, -4.0);
+    /// assert_eq!(complex.l1_norm(), 7.0);
+    /// ```
+    pub fn l1_norm(&self) -> T {
+        self.re.abs() + self.im.abs()
+    }
+
+    /// Returns the principal argument as an `Angle` in radians, within `(-π, π]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let complex = ComplexNumber::from_cartesian(0.0, -2.0);
+    /// assert_eq!(complex.arg().radians(), -std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn arg(&self) -> Angle<T> {
+        Angle::from_radians(self.im.atan2(self.re))
+    }
+
+    /// Returns the unit vector in the direction of this complex number, `z / |z|`. Zero is returned
+    /// as is. An infinite number points along its infinite parts: each infinite part counts as `±1`
+    /// and each finite part as `±0` before normalising.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let complex = ComplexNumber::from_cartesian(3.0, 4.0);
+    /// assert_eq!(complex.signum(), ComplexNumber::from_cartesian(0.6, 0.8));
+    /// assert_eq!(ComplexNumber::from_real(0.0).signum(), ComplexNumber::from_real(0.0));
+    ///
+    /// let infinite = ComplexNumber::from_cartesian(f64::INFINITY, 1.0);
+    /// assert_eq!(infinite.signum(), ComplexNumber::from_cartesian(1.0, 0.0));
+    /// let diagonal = ComplexNumber::from_cartesian(f64::NEG_INFINITY, f64::INFINITY).signum();
+    /// assert_eq!(diagonal.arg().degrees(), 135.0);
+    /// ```
+    pub fn signum(&self) -> ComplexNumber<T> {
+        if self.re == T::ZERO && self.im == T::ZERO {
+            return *self;
+        }
+        if self.is_infinite() {
+            let unit = |x: T| if x.is_infinite() { T::ONE.copysign(x) } else { T::ZERO.copysign(x) };
+            return ComplexNumber::from_cartesian(unit(self.re), unit(self.im)).signum();
+        }
+        self.unscale(self.abs())
+    }
+
+    /// Multiplies both parts by a real number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let complex = ComplexNumber::from_cartesian(3.0, 4.0);
+    /// assert_eq!(complex.scale(2.0), ComplexNumber::from_cartesian(6.0, 8.0));
+    /// ```
+    pub fn scale(&self, t: T) -> ComplexNumber<T> {
+        ComplexNumber::from_cartesian(self.re * t, self.im * t)
+    }
+
+    /// Divides both parts by a real number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let complex = ComplexNumber::from_cartesian(3.0, 4.0);
+    /// assert_eq!(complex.unscale(2.0), ComplexNumber::from_cartesian(1.5, 2.0));
+    /// ```
+    pub fn unscale(&self, t: T) -> ComplexNumber<T> {
+        ComplexNumber::from_cartesian(self.re / t, self.im / t)
+    }
+
+    /// Returns `self * a + b`, computed in Cartesian form. This is the step of Horner's scheme for
+    /// evaluating polynomials and of multiply-accumulate loops.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - A reference to the complex number to multiply by.
+    /// * `b` - A reference to the complex number to add.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// // 2z² - z + 3 at z = i, by Horner's scheme.
+    /// let z = ComplexNumber::from_cartesian(0.0, 1.0);
+    /// let value = ComplexNumber::from_real(2.0)
+    ///     .mul_add(&z, &ComplexNumber::from_real(-1.0))
+    ///     .mul_add(&z, &ComplexNumber::from_real(3.0));
+    /// assert_eq!(value, ComplexNumber::from_cartesian(1.0, -1.0));
+    /// ```
+    pub fn mul_add(&self, a: &ComplexNumber<T>, b: &ComplexNumber<T>) -> ComplexNumber<T> {
+        ComplexNumber::from_cartesian(
+            self.re * a.re - self.im * a.im + b.re,
+            self.re * a.im + self.im * a.re + b.im,
+        )
     }
 
     /// Adds the given complex number to this complex number.
@@ -284,7 +827,7 @@ impl ComplexNumber {
     /// assert_eq!(result.real(), 4.0);
     /// assert_eq!(result.imag(), 6.0);
     /// ```
-    pub fn add(&self, z2: &ComplexNumber) -> ComplexNumber {
+    pub fn add(&self, z2: &ComplexNumber<T>) -> ComplexNumber<T> {
         let real = self.real() + z2.real();
         let imaginary = self.imag() + z2.imag();
         ComplexNumber::from_cartesian(real, imaginary)
@@ -311,7 +854,7 @@ impl ComplexNumber {
     /// assert_eq!(result.real(), 2.0);
     /// assert_eq!(result.imag(), 2.0);
     /// ```
-    pub fn sub(&self, z2: &ComplexNumber) -> ComplexNumber {
+    pub fn sub(&self, z2: &ComplexNumber<T>) -> ComplexNumber<T> {
         let real = self.real() - z2.real();
         let imaginary = self.imag() - z2.imag();
         ComplexNumber::from_cartesian(real, imaginary)
@@ -335,13 +878,20 @@ impl ComplexNumber {
     /// let z1 = ComplexNumber::from_polar(2.0, Angle::from_degrees(30.0));
     /// let z2 = ComplexNumber::from_polar(3.0, Angle::from_degrees(45.0));
     /// let result = z1.mul(&z2);
-    /// assert_eq!(result.abs(), 6.0);
-    /// assert_eq!(result.angle_in_degs(), 75.0);
+    /// let expected = ComplexNumber::from_polar(6.0, Angle::from_degrees(75.0));
+    /// assert!(result.approx_eq(&expected, approx::Tolerance::Relative(1e-15)));
+    /// assert_eq!(result.angle_in_degs_rounded(3), 75.0);
+    ///
+    /// let z3 = ComplexNumber::from_cartesian(1.0, 2.0);
+    /// let z4 = ComplexNumber::from_cartesian(3.0, -4.0);
+    /// let exact = z3.mul(&z4);
+    /// assert_eq!(exact.real(), 11.0);
+    /// assert_eq!(exact.imag(), 2.0);
     /// ```
-    pub fn mul(&self, z2: &ComplexNumber) -> ComplexNumber {
-        let magnitude = self.abs() * z2.abs();
-        let angle = Angle::from_radians(self.angle_in_rads() + z2.angle_in_rads());
-        ComplexNumber::from_polar(magnitude, angle)
+    pub fn mul(&self, z2: &ComplexNumber<T>) -> ComplexNumber<T> {
+        let real = self.real() * z2.real() - self.imag() * z2.imag();
+        let imaginary = self.real() * z2.imag() + self.imag() * z2.real();
+        ComplexNumber::from_cartesian(real, imaginary)
     }
 
     /// Multiplies this complex number by the given scalar value.
@@ -359,14 +909,13 @@ impl ComplexNumber {
     /// ```
     /// use complexible::complex_numbers::*;
     ///
-    /// let z1 = ComplexNumber::from_polar(2.0, Angle::from_degrees(30.0));
+    /// let z1 = ComplexNumber::from_cartesian(1.5, -2.0);
     /// let result = z1.mul_n(3.0);
-    /// assert_eq!(result.abs(), 6.0);
-    /// assert_eq!(result.angle_in_degs(), 30.0);
+    /// assert_eq!(result.real(), 4.5);
+    /// assert_eq!(result.imag(), -6.0);
     /// ```
-    pub fn mul_n(&self, n: f64) -> ComplexNumber {
-        let magnitude = self.abs() * n;
-        ComplexNumber::from_polar(magnitude, self.angle_in_angle())
+    pub fn mul_n(&self, n: T) -> ComplexNumber<T> {
+        ComplexNumber::from_cartesian(self.real() * n, self.imag() * n)
     }
 
     /// Divides this complex number by the given complex number.
@@ -378,22 +927,61 @@ impl ComplexNumber {
     /// # Returns
     ///
     /// A new `ComplexNumber` object that represents the result of dividing this complex number by the given complex number.
+    /// A non-zero number divided by zero gives an infinity, as C99 Annex G specifies.
+    ///
+    /// # Accuracy
+    ///
+    /// The division is carried out in Cartesian form with Baudin and Smith's robust variant of Smith's
+    /// algorithm, which scales operands near the overflow and underflow thresholds, so the quotient is
+    /// accurate to a few units in the last place across the whole exponent range. The ten hard cases
+    /// from their paper, where the textbook formula overflows, underflows or loses every digit, all come
+    /// out within one unit in the last place:
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// // 2^e, including the subnormal range.
+    /// let p = |e: i32| match e {
+    ///     -1074..=-1023 => f64::from_bits(1 << (e + 1074)),
+    ///     _ => f64::from_bits(((e + 1023) as u64) << 52),
+    /// };
+    /// let corpus = [
+    ///     ((1.0, 1.0), (1.0, p(1023)), (p(-1023), -p(-1023))),
+    ///     ((1.0, 1.0), (p(-1023), p(-1023)), (p(1023), 0.0)),
+    ///     ((p(1023), p(-1023)), (p(677), p(-677)), (p(346), -p(-1008))),
+    ///     ((p(1023), p(1023)), (1.0, 1.0), (p(1023), 0.0)),
+    ///     ((p(1020), p(-844)), (p(656), p(-780)), (p(364), -p(-1072))),
+    ///     ((p(-71), p(1021)), (p(1001), p(-323)), (p(-1072), p(20))),
+    ///     ((p(-347), p(-54)), (p(-1037), p(-1058)), (3.8981256045591133e289, 8.174961907852354e295)),
+    ///     ((p(-1074), p(-1074)), (p(-1073), p(-1074)), (0.6, 0.2)),
+    ///     ((p(1015), p(-989)), (p(1023), p(1023)), (0.001953125, -0.001953125)),
+    ///     ((p(-622), p(-1071)), (p(-343), p(-798)), (1.0295115178936058e-84, 6.971459875150762e-220)),
+    /// ];
+    /// for ((a, b), (c, d), (e, f)) in corpus {
+    ///     let quotient = ComplexNumber::from_cartesian(a, b).div(&ComplexNumber::from_cartesian(c, d));
+    ///     assert!(quotient.ulps_eq(&ComplexNumber::from_cartesian(e, f), 1));
+    /// }
+    /// ```
     ///
     /// # Example
     ///
     /// ```
     /// use complexible::complex_numbers::*;
     ///
-    /// let z1 = ComplexNumber::from_polar(2.0, Angle::from_degrees(30.0));
+    /// let z1 = ComplexNumber::from_cartesian(1.0, 2.0);
+    /// let z2 = ComplexNumber::from_cartesian(3.0, 4.0);
+    /// let result = z1.div(&z2);
+    /// assert_eq!(result, ComplexNumber::from_cartesian(0.44, 0.08));
+    ///
+    /// let z1 = ComplexNumber::from_polar(2.0_f64, Angle::from_degrees(30.0));
     /// let z2 = ComplexNumber::from_polar(3.0, Angle::from_degrees(45.0));
     /// let result = z1.div(&z2);
-    /// assert_eq!(result.abs(), 2.0/3.0);
-    /// assert_eq!(result.angle_in_degs(), -15.0);
+    /// assert!((result.abs() - 2.0 / 3.0).abs() < 1e-15);
+    /// assert_eq!(result.angle_in_degs_rounded(3), -15.0);
     /// ```
-    pub fn div(&self, z2: &ComplexNumber) -> ComplexNumber {
-        let magnitude = self.abs() / z2.abs();
-        let angle = Angle::from_radians(self.angle_in_rads() - z2.angle_in_rads());
-        ComplexNumber::from_polar(magnitude, angle)
+    pub fn div(&self, z2: &ComplexNumber<T>) -> ComplexNumber<T> {
+        let (re, im) = division::divide(self.re, self.im, z2.re, z2.im);
+        ComplexNumber::from_cartesian(re, im)
     }
 
     /// Raises this complex number to the given power.
@@ -414,14 +1002,115 @@ impl ComplexNumber {
     /// let z1 = ComplexNumber::from_polar(2.0, Angle::from_degrees(30.0));
     /// let result = z1.pow(2.0);
     /// assert_eq!(result.abs(), 4.0);
-    /// assert_eq!(result.angle_in_degs(), 60.0);
+    /// assert_eq!(result.angle_in_degs_rounded(3), 60.0);
     /// ```
-    pub fn pow(&self, n: f64) -> ComplexNumber {
+    pub fn pow(&self, n: T) -> ComplexNumber<T> {
         let magnitude = self.abs().powf(n);
         let angle = Angle::from_radians(self.angle_in_rads() * n);
         ComplexNumber::from_polar(magnitude, angle)
     }
 
+    /// Raises this complex number to an integer power by repeated squaring in Cartesian form.
+    ///
+    /// No trigonometry is involved, so small integer powers of exactly representable inputs are exact.
+    /// Negative powers take the reciprocal of the positive power with `inv`, so that it does not overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The integer power to raise this complex number to.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents the result of raising this complex number to the given power.
+    /// Any number raised to the power `0` is `1`, and zero raised to a negative power is a real infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(1.0, 1.0);
+    /// let result = z1.powi(4);
+    /// assert_eq!(result.real(), -4.0);
+    /// assert_eq!(result.imag(), 0.0);
+    ///
+    /// let inverse = z1.powi(-2);
+    /// assert_eq!(inverse.real(), 0.0);
+    /// assert_eq!(inverse.imag(), -0.5);
+    ///
+    /// let large = ComplexNumber::from_cartesian(1e200, 1e200).powi(-1);
+    /// assert_eq!(large, ComplexNumber::from_cartesian(5e-201, -5e-201));
+    /// assert_eq!(ComplexNumber::from_real(0.0).powi(-1), ComplexNumber::INFINITY);
+    /// ```
+    pub fn powi(&self, n: i32) -> ComplexNumber<T> {
+        let mut base = (self.real(), self.imag());
+        let mut result = (T::ONE, T::ZERO);
+        let mut exponent = n.unsigned_abs();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = (
+                    result.0 * base.0 - result.1 * base.1,
+                    result.0 * base.1 + result.1 * base.0,
+                );
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = (base.0 * base.0 - base.1 * base.1, T::from_f64(2.0) * base.0 * base.1);
+            }
+        }
+        let power = ComplexNumber::from_cartesian(result.0, result.1);
+        if n >= 0 {
+            power
+        } else if power.is_zero() {
+            ComplexNumber::INFINITY
+        } else {
+            power.inv()
+        }
+    }
+
+    /// Raises this complex number to a complex power, `z^w = e^(w·ln z)`, on the principal branch of `ln`.
+    ///
+    /// # Arguments
+    ///
+    /// * `w` - A reference to the complex exponent.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents the principal value of this complex number raised to `w`.
+    /// `z^0` is `1` for every `z` (including zero). `0^w` is `0` when the real part of `w` is positive and
+    /// a real infinity when it is negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// // i^i = e^(-π/2)
+    /// let i = ComplexNumber::from_cartesian(0.0, 1.0);
+    /// let result = i.powc(&i);
+    /// assert!((result.real() - (-PI / 2.0).exp()).abs() < 1e-15);
+    /// assert_eq!(result.imag(), 0.0);
+    ///
+    /// let zero = ComplexNumber::from_real(0.0);
+    /// assert_eq!(zero.powc(&ComplexNumber::from_cartesian(2.0, 1.0)), zero);
+    /// assert_eq!(zero.powc(&ComplexNumber::from_real(-1.0)), ComplexNumber::INFINITY);
+    /// ```
+    pub fn powc(&self, w: &ComplexNumber<T>) -> ComplexNumber<T> {
+        if w.real() == T::ZERO && w.imag() == T::ZERO {
+            return ComplexNumber::from_real(T::ONE);
+        }
+        if self.real() == T::ZERO && self.imag() == T::ZERO {
+            if w.real() > T::ZERO {
+                return ComplexNumber::ZERO;
+            }
+            if w.real() < T::ZERO {
+                return ComplexNumber::INFINITY;
+            }
+        }
+        w.mul(&self.ln()).exp()
+    }
+
     /// Calculates the nth root of this complex number.
     ///
     /// # Arguments
@@ -440,56 +1129,183 @@ impl ComplexNumber {
     /// let z1 = ComplexNumber::from_polar(2.0, Angle::from_degrees(30.0));
     /// let result = z1.nth_root(2.0);
     /// assert_eq!(result.abs(), 1.4142135623730951);
-    /// assert_eq!(result.angle_in_degs(), 15.0);
+    /// assert_eq!(result.angle_in_degs_rounded(3), 15.0);
     /// ```
-    pub fn nth_root(&self, n: f64) -> ComplexNumber {
-        let magnitude: f64 = self.abs().powf(1.0 / n);
+    pub fn nth_root(&self, n: T) -> ComplexNumber<T> {
+        let magnitude: T = self.abs().powf(T::ONE / n);
         let angle = Angle::from_radians(self.angle_in_rads() / n);
         ComplexNumber::from_polar(magnitude, angle)
     }
 
-    /// Calculates the natural logarithm of this complex number.
+    /// Calculates all `n` distinct nth roots of this complex number.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The root to calculate.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of `n` `ComplexNumber` objects. Root `k` is `|z|^(1/n)·e^(i(Arg z + 2πk)/n)`, so the roots
+    /// start at the principal root (the one with `Arg z` in `(-π, π]` divided by `n`) and proceed
+    /// counter-clockwise. For `n == 0` the `Vec` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1: Complex64 = ComplexNumber::from_cartesian(-8.0, 0.0);
+    /// let roots = z1.nth_roots(3);
+    /// assert_eq!(roots.len(), 3);
+    /// assert!((roots[0].real() - 1.0).abs() < 1e-15);
+    /// assert!((roots[0].imag() - 3.0_f64.sqrt()).abs() < 1e-15);
+    /// assert!((roots[1].real() + 2.0).abs() < 1e-15);
+    /// for root in &roots {
+    ///     let cube = root.mul(root).mul(root);
+    ///     assert!((cube.real() + 8.0).abs() < 1e-13 && cube.imag().abs() < 1e-13);
+    /// }
+    /// ```
+    pub fn nth_roots(&self, n: u32) -> Vec<ComplexNumber<T>> {
+        let count = T::from_f64(f64::from(n));
+        let magnitude = self.real().hypot(self.imag()).powf(T::ONE / count);
+        let argument = self.imag().atan2(self.real());
+        (0..n)
+            .map(|k| {
+                let turn = T::from_f64(2.0 * f64::from(k)) * T::PI;
+                ComplexNumber::from_polar(magnitude, Angle::from_radians((argument + turn) / count))
+            })
+            .collect()
+    }
+
+    /// Calculates the `n` nth roots of unity, `e^(2πik/n)` for `k = 0, 1, …, n - 1`.
+    ///
+    /// The roots are returned in counter-clockwise order starting at `1`, which is the order FFT twiddle
+    /// factors are usually indexed in. Roots that fall on the axes (`k/n` a multiple of a quarter turn)
+    /// are exact. For `n == 0` the `Vec` is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The order of the roots.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let roots: Vec<Complex64> = ComplexNumber::roots_of_unity(4);
+    /// assert_eq!(roots[0], ComplexNumber::from_cartesian(1.0, 0.0));
+    /// assert_eq!(roots[1].real(), 0.0);
+    /// assert_eq!(roots[1].imag(), 1.0);
+    /// assert_eq!(roots[2].real(), -1.0);
+    /// assert_eq!(roots[3].imag(), -1.0);
+    /// ```
+    pub fn roots_of_unity(n: u32) -> Vec<ComplexNumber<T>> {
+        (0..n)
+            .map(|k| {
+                let quarters = 4 * u64::from(k);
+                if quarters % u64::from(n) == 0 {
+                    return match quarters / u64::from(n) {
+                        0 => ComplexNumber::from_cartesian(T::ONE, T::ZERO),
+                        1 => ComplexNumber::from_cartesian(T::ZERO, T::ONE),
+                        2 => ComplexNumber::from_cartesian(-T::ONE, T::ZERO),
+                        _ => ComplexNumber::from_cartesian(T::ZERO, -T::ONE),
+                    };
+                }
+                let angle = T::from_f64(2.0 * f64::from(k) / f64::from(n)) * T::PI;
+                ComplexNumber::from_cartesian(angle.cos(), angle.sin())
+            })
+            .collect()
+    }
+
+    /// Calculates the principal natural logarithm of this complex number, `ln|z| + i·Arg z`.
     ///
     /// # Returns
     ///
     /// A new `ComplexNumber` object that represents the natural logarithm of this complex number.
+    /// The imaginary part (the principal argument) lies in `[-π, π]`.
+    ///
+    /// # Branch Cut
+    ///
+    /// The branch cut lies along the negative real axis. The sign of a zero imaginary part selects the
+    /// side of the cut: `-1 + 0i` maps to `+iπ` while `-1 - 0i` maps to `-iπ`. The logarithm of zero is
+    /// `-∞ + i·Arg 0`.
     ///
     /// # Example
     ///
     /// ```
     /// use complexible::complex_numbers::*;
+    /// use std::f64::consts::{LN_2, PI};
     ///
     /// let z1 = ComplexNumber::from_polar(2.0, Angle::from_degrees(30.0));
     /// let result = z1.ln();
-    /// assert_eq!(result.real(), 0.6002830669264718);
-    /// assert_eq!(result.imag(), 0.3465735902799726);
+    /// assert!((result.real() - LN_2).abs() < 1e-15);
+    /// assert!((result.imag() - PI / 6.0).abs() < 1e-15);
+    ///
+    /// let above = ComplexNumber::from_cartesian(-1.0, 0.0).ln();
+    /// let below = ComplexNumber::from_cartesian(-1.0, -0.0).ln();
+    /// assert_eq!(above.imag(), PI);
+    /// assert_eq!(below.imag(), -PI);
     /// ```
+    pub fn ln(&self) -> ComplexNumber<T> {
+        let magnitude = self.real().hypot(self.imag());
+        let argument = self.imag().atan2(self.real());
+        ComplexNumber::from_cartesian(magnitude.ln(), argument)
+    }
+
+    /// Calculates the natural logarithm of this complex number on branch `k`, `ln|z| + i·(Arg z + 2πk)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The branch index. Branch `0` is the principal branch returned by `ln`.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents the logarithm of this complex number on the given branch.
+    /// Its imaginary part lies in `[(2k - 1)π, (2k + 1)π]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    /// use std::f64::consts::PI;
     ///
-    pub fn ln(&self) -> ComplexNumber {
-        let magnitude = self.abs().ln();
-        ComplexNumber::from_polar(magnitude, self.angle_in_angle())
+    /// let z1 = ComplexNumber::from_cartesian(0.0, 1.0);
+    /// assert_eq!(z1.ln_k(0).imag(), PI / 2.0);
+    /// assert_eq!(z1.ln_k(1).imag(), PI / 2.0 + 2.0 * PI);
+    /// assert_eq!(z1.ln_k(-1).imag(), PI / 2.0 - 2.0 * PI);
+    /// ```
+    pub fn ln_k(&self, k: i32) -> ComplexNumber<T> {
+        let principal = self.ln();
+        let two_pi_k = T::from_f64(2.0 * f64::from(k)) * T::PI;
+        ComplexNumber::from_cartesian(principal.real(), principal.imag() + two_pi_k)
     }
-    /// Calculates the base-10 logarithm of this complex number.
+
+    /// Calculates the principal base-10 logarithm of this complex number, `ln z / ln 10`.
     ///
     /// # Returns
     ///
     /// A new `ComplexNumber` object that represents the base-10 logarithm of this complex number.
+    /// It shares the branch cut of `ln`.
     ///
     /// # Example
     ///
     /// ```
     /// use complexible::complex_numbers::*;
+    /// use std::f64::consts::{LN_10, PI};
     ///
-    /// let z1 = ComplexNumber::from_polar(2.0, Angle::from_degrees(30.0));
+    /// let z1 = ComplexNumber::from_cartesian(-100.0, 0.0);
     /// let result = z1.log10();
-    /// assert_eq!(result.real(), 0.26069962354612713);
-    /// assert_eq!(result.imag(), 0.15051499783199057);
+    /// assert_eq!(result.real(), 2.0);
+    /// assert_eq!(result.imag(), PI / LN_10);
     /// ```
-    pub fn log10(&self) -> ComplexNumber {
-        let magnitude = self.abs().log10();
-        ComplexNumber::from_polar(magnitude, self.angle_in_angle())
+    pub fn log10(&self) -> ComplexNumber<T> {
+        let ln = self.ln();
+        let ln_10 = T::from_f64(10.0).ln();
+        ComplexNumber::from_cartesian(ln.real() / ln_10, ln.imag() / ln_10)
     }
-    /// Calculates the logarithm of this complex number with respect to an arbitrary base.
+
+    /// Calculates the principal logarithm of this complex number with respect to an arbitrary real base,
+    /// `ln z / ln arb`.
     ///
     /// # Arguments
     ///
@@ -498,86 +1314,81 @@ impl ComplexNumber {
     /// # Returns
     ///
     /// A new `ComplexNumber` object that represents the logarithm of this complex number with respect to the given base.
+    /// It shares the branch cut of `ln`.
     ///
     /// # Example
     ///
     /// ```
     /// use complexible::complex_numbers::*;
+    /// use std::f64::consts::{LN_2, PI};
     ///
-    /// let z1 = ComplexNumber::from_polar(2.0, Angle::from_degrees(30.0));
+    /// let z1 = ComplexNumber::from_cartesian(0.0, 8.0);
     /// let result = z1.log(2.0);
-    /// assert_eq!(result.real(), 0.8660254037844387);
-    /// assert_eq!(result.imag(), 0.49999999999999994);
+    /// assert_eq!(result.real(), 3.0);
+    /// assert_eq!(result.imag(), PI / 2.0 / LN_2);
     /// ```
-    pub fn log(&self, arb: f64) -> ComplexNumber {
-        let magnitude = self.abs().log(arb);
-        ComplexNumber::from_polar(magnitude, self.angle_in_angle())
+    pub fn log(&self, arb: T) -> ComplexNumber<T> {
+        let ln = self.ln();
+        let ln_base = arb.ln();
+        ComplexNumber::from_cartesian(ln.real() / ln_base, ln.imag() / ln_base)
     }
 
+    /// Calculates the principal logarithm of this complex number with respect to a complex base,
+    /// `ln z / ln base`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - A reference to the complex base to calculate the logarithm with respect to.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents the logarithm of this complex number with respect to the given base.
+    /// Both logarithms are taken on the principal branch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::{assert_approx_eq, complex_numbers::*};
+    ///
+    /// let z1: Complex64 = ComplexNumber::from_cartesian(-1.0, 0.0);
+    /// let base = ComplexNumber::from_cartesian(0.0, 1.0);
+    /// let result = z1.logc(&base);
+    /// assert_approx_eq!(result, ComplexNumber::from_real(2.0), abs = 1e-15);
+    /// ```
+    pub fn logc(&self, base: &ComplexNumber<T>) -> ComplexNumber<T> {
+        self.ln().div(&base.ln())
+    }
 
     pub fn print_cartesian(&self) {
-        print!("cartesian form: {} + {} j", self.real(), self.imag());
+        print!("cartesian form: {}", self);
     }
     pub fn print_polar(&self) {
+        let polar = format::ComplexFormat::new();
         println!(
-            "polar form (radian): {} e ^ {} ㎭ j",
-            self.abs(),
-            self.angle_in_rads()
+            "polar form (radian): {}",
+            self.format_with(polar.form(format::Form::PolarRadians))
         );
         println!(
-            "polar form (degree): {} e ^ {}° j",
-            self.abs(),
-            self.angle_in_degs()
+            "polar form (degree): {}",
+            self.format_with(polar.form(format::Form::PolarDegrees))
         );
-    } 
-}
-impl fmt::Display for ComplexNumber {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mag_as_sqrt = self.polar.magnitude.powi(2);
-        writeln!(
-            f,
-            "
-            Pretty Values: 
-            Cartesian Form (Pretty): {:.1} + {:.1} j 
-            Polar Form (Pretty): √{:.0} e ^ {:.1}㎭ j
-            Polar Form (Pretty): √{:.0} e ^ {:.1}° j 
-            
-            Precision Values:
-            Cartesian Form (Pretty): {} + {} j 
-            Polar Form (Pretty): {} e ^ {}㎭ j
-            Polar Form (Pretty): {} e ^ {}° j ",
-            self.cartesian.real,
-            self.cartesian.imaginary,
-            mag_as_sqrt,
-            self.polar.angle.r.value,
-            mag_as_sqrt,
-            self.polar.angle.d.value,
-            self.cartesian.real,
-            self.cartesian.imaginary,
-            self.polar.magnitude,
-            self.polar.angle.r.value,
-            self.polar.magnitude,
-            self.polar.angle.d.value,
-        )
     }
 }
 
-impl PartialEq for ComplexNumber {
+/// Exact component equality: two complex numbers are equal when their real parts and their imaginary
+/// parts are equal as floating-point values. Use the methods of the `approx` module to compare with a tolerance.
+impl<T: Float> PartialEq for ComplexNumber<T> {
     fn eq(&self, other: &Self) -> bool {
-        let c1 = round_five_zeros(self.abs()) == round_five_zeros(other.abs());
-        let c2 = round_five_zeros(self.angle_in_degs()) == round_five_zeros(other.angle_in_degs());
-        let c3 = round_five_zeros(self.angle_in_rads()) == round_five_zeros(other.angle_in_rads());
-        let c4 = round_five_zeros(self.imag()) == round_five_zeros(other.imag());
-        let c5 = round_five_zeros(self.real()) == round_five_zeros(other.real());
-
-        c1 && c2 && c3 && c4 && c5
+        self.real() == other.real() && self.imag() == other.imag()
     }
 }
-fn round_five_zeros(n: f64) -> f64 {
-    let five_zeros = 1_00000_f64;
-    (n * five_zeros).round() / five_zeros
-}
-fn round_three_zeros(n: f64) -> f64 {
-    let three_zeros = 1_000_f64;
-    (n * three_zeros).round() / three_zeros
+/// Rounds `n` to the given number of decimal places. Values that are already integers at that scale,
+/// or whose scaled value would overflow, are returned unchanged.
+fn round_decimals<T: Float>(n: T, decimals: u32) -> T {
+    let scale = T::from_f64(10_f64.powi(i32::try_from(decimals).unwrap_or(i32::MAX)));
+    let scaled = n * scale;
+    if !scale.is_finite() || !scaled.is_finite() || scaled.abs() >= T::ONE / T::EPSILON {
+        return n;
+    }
+    scaled.round() / scale
 }
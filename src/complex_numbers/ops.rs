@@ -0,0 +1,212 @@
+//! Operator overloads (`+`, `-`, `*`, `/`, unary `-` and their `*Assign` forms) for `ComplexNumber`,
+//! and the `Sum` and `Product` iterator folds built on them.
+//!
+//! Every binary operator is available for owned values and references on either side, and for
+//! mixed `ComplexNumber`/scalar operands, so that expressions such as `2.0 * &z - 1.0` compile.
+//! The implementations delegate to the named methods (`add`, `sub`, `mul`, `div`) on `ComplexNumber`.
+
+use super::*;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Implements a binary operator between two complex numbers for every owned/borrowed combination,
+/// forwarding to the given inherent method.
+macro_rules! complex_binop {
+    ($trait:ident, $fn:ident, $method:ident) => {
+        impl<T: Float> $trait<&ComplexNumber<T>> for &ComplexNumber<T> {
+            type Output = ComplexNumber<T>;
+            fn $fn(self, rhs: &ComplexNumber<T>) -> ComplexNumber<T> {
+                ComplexNumber::$method(self, rhs)
+            }
+        }
+        impl<T: Float> $trait<ComplexNumber<T>> for &ComplexNumber<T> {
+            type Output = ComplexNumber<T>;
+            fn $fn(self, rhs: ComplexNumber<T>) -> ComplexNumber<T> {
+                ComplexNumber::$method(self, &rhs)
+            }
+        }
+        impl<T: Float> $trait<&ComplexNumber<T>> for ComplexNumber<T> {
+            type Output = ComplexNumber<T>;
+            fn $fn(self, rhs: &ComplexNumber<T>) -> ComplexNumber<T> {
+                ComplexNumber::$method(&self, rhs)
+            }
+        }
+        impl<T: Float> $trait<ComplexNumber<T>> for ComplexNumber<T> {
+            type Output = ComplexNumber<T>;
+            fn $fn(self, rhs: ComplexNumber<T>) -> ComplexNumber<T> {
+                ComplexNumber::$method(&self, &rhs)
+            }
+        }
+    };
+}
+
+/// Implements a binary operator between a complex number and a real scalar, with the scalar on the
+/// right, for owned and borrowed complex operands. `$zn` computes `z op n`.
+macro_rules! scalar_binop {
+    ($trait:ident, $fn:ident, $zn:expr) => {
+        impl<T: Float> $trait<T> for &ComplexNumber<T> {
+            type Output = ComplexNumber<T>;
+            fn $fn(self, rhs: T) -> ComplexNumber<T> {
+                ($zn)(self, rhs)
+            }
+        }
+        impl<T: Float> $trait<T> for ComplexNumber<T> {
+            type Output = ComplexNumber<T>;
+            fn $fn(self, rhs: T) -> ComplexNumber<T> {
+                ($zn)(&self, rhs)
+            }
+        }
+    };
+}
+
+/// Implements a binary operator with a primitive scalar on the left and a complex number on the right.
+/// Coherence rules forbid a blanket `impl<T> Op<ComplexNumber<T>> for T`, so this is instantiated
+/// once per primitive type. `$nz` computes `n op z`.
+macro_rules! scalar_lhs_binop {
+    ($t:ty, $trait:ident, $fn:ident, $nz:expr) => {
+        impl $trait<&ComplexNumber<$t>> for $t {
+            type Output = ComplexNumber<$t>;
+            fn $fn(self, rhs: &ComplexNumber<$t>) -> ComplexNumber<$t> {
+                ($nz)(self, rhs)
+            }
+        }
+        impl $trait<ComplexNumber<$t>> for $t {
+            type Output = ComplexNumber<$t>;
+            fn $fn(self, rhs: ComplexNumber<$t>) -> ComplexNumber<$t> {
+                ($nz)(self, &rhs)
+            }
+        }
+    };
+}
+
+/// Implements every left-hand scalar operator for a primitive type.
+macro_rules! scalar_lhs_ops {
+    ($t:ty) => {
+        scalar_lhs_binop!($t, Add, add, |n: $t, z: &ComplexNumber<$t>| {
+            ComplexNumber::from_cartesian(n + z.real(), z.imag())
+        });
+        scalar_lhs_binop!($t, Sub, sub, |n: $t, z: &ComplexNumber<$t>| {
+            ComplexNumber::from_cartesian(n - z.real(), -z.imag())
+        });
+        scalar_lhs_binop!($t, Mul, mul, |n: $t, z: &ComplexNumber<$t>| {
+            ComplexNumber::from_cartesian(n * z.real(), n * z.imag())
+        });
+        scalar_lhs_binop!($t, Div, div, |n: $t, z: &ComplexNumber<$t>| {
+            ComplexNumber::div(&ComplexNumber::from_real(n), z)
+        });
+    };
+}
+
+/// Implements a compound assignment operator in terms of the matching binary operator on references.
+macro_rules! assign_op {
+    ($trait:ident, $fn:ident, $op:tt) => {
+        impl<T: Float> $trait<&ComplexNumber<T>> for ComplexNumber<T> {
+            fn $fn(&mut self, rhs: &ComplexNumber<T>) {
+                *self = &*self $op rhs;
+            }
+        }
+        impl<T: Float> $trait<ComplexNumber<T>> for ComplexNumber<T> {
+            fn $fn(&mut self, rhs: ComplexNumber<T>) {
+                *self = &*self $op &rhs;
+            }
+        }
+        impl<T: Float> $trait<T> for ComplexNumber<T> {
+            fn $fn(&mut self, rhs: T) {
+                *self = &*self $op rhs;
+            }
+        }
+    };
+}
+
+complex_binop!(Add, add, add);
+complex_binop!(Sub, sub, sub);
+complex_binop!(Mul, mul, mul);
+complex_binop!(Div, div, div);
+
+scalar_binop!(Add, add, |z: &ComplexNumber<T>, n: T| {
+    ComplexNumber::from_cartesian(z.real() + n, z.imag())
+});
+scalar_binop!(Sub, sub, |z: &ComplexNumber<T>, n: T| {
+    ComplexNumber::from_cartesian(z.real() - n, z.imag())
+});
+scalar_binop!(Mul, mul, |z: &ComplexNumber<T>, n: T| {
+    ComplexNumber::from_cartesian(z.real() * n, z.imag() * n)
+});
+scalar_binop!(Div, div, |z: &ComplexNumber<T>, n: T| {
+    ComplexNumber::from_cartesian(z.real() / n, z.imag() / n)
+});
+
+scalar_lhs_ops!(f32);
+scalar_lhs_ops!(f64);
+
+assign_op!(AddAssign, add_assign, +);
+assign_op!(SubAssign, sub_assign, -);
+assign_op!(MulAssign, mul_assign, *);
+assign_op!(DivAssign, div_assign, /);
+
+impl<T: Float> Neg for &ComplexNumber<T> {
+    type Output = ComplexNumber<T>;
+    fn neg(self) -> ComplexNumber<T> {
+        ComplexNumber::from_cartesian(-self.real(), -self.imag())
+    }
+}
+impl<T: Float> Neg for ComplexNumber<T> {
+    type Output = ComplexNumber<T>;
+    fn neg(self) -> ComplexNumber<T> {
+        -&self
+    }
+}
+
+/// Adds up the complex numbers of an iterator, left to right. The sum of an empty iterator is zero.
+/// For long sequences of values with differing magnitudes, `summation::CompensatedSum` is more
+/// accurate.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::*;
+///
+/// let phasors = [
+///     ComplexNumber::from_cartesian(1.0, 2.0),
+///     ComplexNumber::from_cartesian(3.0, -1.0),
+///     ComplexNumber::from_cartesian(-0.5, 0.5),
+/// ];
+/// let by_reference: ComplexNumber = phasors.iter().sum();
+/// let by_value: ComplexNumber = phasors.into_iter().sum();
+/// assert_eq!(by_reference, ComplexNumber::from_cartesian(3.5, 1.5));
+/// assert_eq!(by_value, by_reference);
+/// ```
+impl<T: Float> Sum for ComplexNumber<T> {
+    fn sum<I: Iterator<Item = ComplexNumber<T>>>(iter: I) -> ComplexNumber<T> {
+        iter.fold(ComplexNumber::ZERO, |acc, z| acc + z)
+    }
+}
+impl<'a, T: Float> Sum<&'a ComplexNumber<T>> for ComplexNumber<T> {
+    fn sum<I: Iterator<Item = &'a ComplexNumber<T>>>(iter: I) -> ComplexNumber<T> {
+        iter.fold(ComplexNumber::ZERO, |acc, z| acc + z)
+    }
+}
+
+/// Multiplies together the complex numbers of an iterator, left to right. The product of an empty
+/// iterator is one.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::*;
+///
+/// let i = ComplexNumber::from_cartesian(0.0, 1.0);
+/// let product: ComplexNumber = [i, i, i].iter().product();
+/// assert_eq!(product, ComplexNumber::from_cartesian(0.0, -1.0));
+/// assert_eq!(std::iter::empty::<ComplexNumber>().product::<ComplexNumber>(), ComplexNumber::from_real(1.0));
+/// ```
+impl<T: Float> Product for ComplexNumber<T> {
+    fn product<I: Iterator<Item = ComplexNumber<T>>>(iter: I) -> ComplexNumber<T> {
+        iter.fold(ComplexNumber::ONE, |acc, z| acc * z)
+    }
+}
+impl<'a, T: Float> Product<&'a ComplexNumber<T>> for ComplexNumber<T> {
+    fn product<I: Iterator<Item = &'a ComplexNumber<T>>>(iter: I) -> ComplexNumber<T> {
+        iter.fold(ComplexNumber::ONE, |acc, z| acc * z)
+    }
+}
@@ -0,0 +1,478 @@
+//! # `parse` Module
+//!
+//! This module implements `FromStr` for `ComplexNumber` and `Angle`, so complex values can be read back from
+//! configuration files, command lines and other text. Three notations are accepted, with optional
+//! whitespace between tokens and either `i` or `j` as the imaginary unit:
+//!
+//! * Cartesian: `3+4j`, `3 - 4i`, `-2.5e3j`, `3+j4`, `7`, `-j`
+//! * Polar (angle notation): `5∠30°`, `5∠30`, `5<0.52rad`, `5<30deg`, `5∠12°30'`. Without a unit the angle is
+//!   in degrees.
+//! * Exponential: `5e^(j0.927)`, `5*e^(0.927i)`, `5e^j0.927`. Without a unit the angle is in radians.
+//!
+//! The polar angle may carry any unit that `Angle::from_str` accepts, including degrees, minutes and
+//! seconds. The exponential angle accepts the same units, as single values.
+//!
+//! Errors report the character position (counted from zero) at which parsing failed.
+//!
+//! ```
+//! use complexible::complex_numbers::*;
+//!
+//! let z: ComplexNumber = "3-4j".parse().unwrap();
+//! assert_eq!(z, ComplexNumber::from_cartesian(3.0, -4.0));
+//!
+//! let p: ComplexNumber = "2∠90°".parse().unwrap();
+//! assert!(p.abs_diff_eq(&ComplexNumber::from_cartesian(0.0, 2.0), 1e-15));
+//!
+//! let err = "3+4k".parse::<ComplexNumber>().unwrap_err();
+//! assert_eq!(err.position(), 3);
+//! assert_eq!(err.to_string(), "unexpected character 'k' at position 3");
+//! ```
+
+use super::*;
+use std::error::Error;
+use std::str::FromStr;
+
+/// The reason a string could not be parsed as a `ComplexNumber` or an `Angle`.
+///
+/// # Variants
+///
+/// * `Empty` - The string is empty or contains only whitespace.
+/// * `UnexpectedEnd` - The string ended where more input was required.
+/// * `UnexpectedCharacter(c)` - The character `c` is not valid at this point.
+/// * `InvalidNumber` - A numeric literal is malformed or out of range for the component type.
+/// * `DuplicateComponent` - Both terms of a Cartesian sum are real, or both are imaginary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseComplexErrorKind {
+    Empty,
+    UnexpectedEnd,
+    UnexpectedCharacter(char),
+    InvalidNumber,
+    DuplicateComponent,
+}
+
+/// The error returned when parsing a `ComplexNumber` or an `Angle` from a string fails.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::{*, parse::*};
+///
+/// let err = "3+4".parse::<ComplexNumber>().unwrap_err();
+/// assert_eq!(err.kind(), ParseComplexErrorKind::DuplicateComponent);
+/// assert_eq!(err.position(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseComplexError {
+    kind: ParseComplexErrorKind,
+    position: usize,
+}
+impl ParseComplexError {
+    /// Returns the reason parsing failed.
+    pub fn kind(&self) -> ParseComplexErrorKind {
+        self.kind
+    }
+
+    /// Returns the character position (counted from zero) at which parsing failed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+impl fmt::Display for ParseComplexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ParseComplexErrorKind::Empty => write!(f, "cannot parse an empty string"),
+            ParseComplexErrorKind::UnexpectedEnd => {
+                write!(f, "unexpected end of input at position {}", self.position)
+            }
+            ParseComplexErrorKind::UnexpectedCharacter(c) => {
+                write!(f, "unexpected character '{}' at position {}", c, self.position)
+            }
+            ParseComplexErrorKind::InvalidNumber => write!(f, "invalid number at position {}", self.position),
+            ParseComplexErrorKind::DuplicateComponent => write!(
+                f,
+                "both terms at position {} have the same component (real or imaginary)",
+                self.position
+            ),
+        }
+    }
+}
+impl Error for ParseComplexError {}
+
+/// One signed term of a Cartesian sum.
+enum Term<T> {
+    Real(T),
+    Imaginary(T),
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn error(&self, kind: ParseComplexErrorKind) -> ParseComplexError {
+        ParseComplexError { kind, position: self.position }
+    }
+
+    fn unexpected(&self) -> ParseComplexError {
+        match self.peek() {
+            Some(c) => self.error(ParseComplexErrorKind::UnexpectedCharacter(c)),
+            None => self.error(ParseComplexErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        let end = self.position + word.chars().count();
+        if end <= self.chars.len() && self.chars[self.position..end].iter().copied().eq(word.chars()) {
+            self.position = end;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_unit(&mut self) -> bool {
+        self.eat('i') || self.eat('j')
+    }
+
+    fn eat_sign(&mut self) -> bool {
+        if self.eat('-') {
+            true
+        } else {
+            self.eat('+');
+            false
+        }
+    }
+
+    fn at_number(&self) -> bool {
+        self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.')
+    }
+
+    /// Scans an unsigned decimal literal such as `2`, `.5` or `2.5e-3`. An `e` is only taken as an
+    /// exponent when a digit (optionally signed) follows it, so `5e^(j1)` leaves `e^` untouched.
+    fn number<T: Float>(&mut self) -> Result<T, ParseComplexError> {
+        let start = self.position;
+        let mut digits = 0;
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() {
+                digits += 1;
+            } else if c != '.' {
+                break;
+            }
+            self.position += 1;
+        }
+        if digits == 0 {
+            let dots = self.position > start;
+            self.position = start;
+            if dots {
+                return Err(self.error(ParseComplexErrorKind::InvalidNumber));
+            }
+            return Err(self.unexpected());
+        }
+        if matches!(self.peek(), Some('e') | Some('E')) {
+            let mut lookahead = self.position + 1;
+            if matches!(self.chars.get(lookahead), Some('+') | Some('-')) {
+                lookahead += 1;
+            }
+            if self.chars.get(lookahead).is_some_and(char::is_ascii_digit) {
+                self.position = lookahead;
+                while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.position += 1;
+                }
+            }
+        }
+        let literal: String = self.chars[start..self.position].iter().collect();
+        literal.parse::<T>().map_err(|_| ParseComplexError {
+            kind: ParseComplexErrorKind::InvalidNumber,
+            position: start,
+        })
+    }
+
+    /// Parses a term with an optional leading sign: a real number, an imaginary number (`4j`, `4*j`,
+    /// `j4`) or a bare imaginary unit.
+    fn term<T: Float>(&mut self) -> Result<Term<T>, ParseComplexError> {
+        let negative = self.eat_sign();
+        self.skip_whitespace();
+        let sign = |value: T| if negative { -value } else { value };
+        if self.eat_unit() {
+            self.skip_whitespace();
+            if self.at_number() {
+                return Ok(Term::Imaginary(sign(self.number()?)));
+            }
+            return Ok(Term::Imaginary(sign(T::ONE)));
+        }
+        if !self.at_number() {
+            return Err(self.unexpected());
+        }
+        let value = self.number()?;
+        let before_unit = self.position;
+        self.skip_whitespace();
+        let starred = self.eat('*');
+        self.skip_whitespace();
+        if self.eat_unit() {
+            return Ok(Term::Imaginary(sign(value)));
+        }
+        // A `*` must be followed by the imaginary unit or by the `e^` of the exponential notation.
+        if starred && self.peek() != Some('e') {
+            return Err(self.unexpected());
+        }
+        self.position = before_unit;
+        Ok(Term::Real(sign(value)))
+    }
+
+    /// Parses an optional angle unit suffix.
+    fn angle_unit(&mut self) -> Option<AngleUnit> {
+        self.skip_whitespace();
+        if self.eat('°') || self.eat_word("deg") {
+            Some(AngleUnit::Degrees)
+        } else if self.eat_word("mrad") {
+            Some(AngleUnit::Milliradians)
+        } else if self.eat_word("rad") || self.eat('㎭') {
+            Some(AngleUnit::Radians)
+        } else if self.eat_word("grad") || self.eat_word("gon") {
+            Some(AngleUnit::Gradians)
+        } else if self.eat_word("turn") || self.eat_word("rev") {
+            Some(AngleUnit::Turns)
+        } else if self.eat_word("arcmin") || self.eat('′') || self.eat('\'') {
+            Some(AngleUnit::Arcminutes)
+        } else if self.eat_word("arcsec") || self.eat('″') || self.eat('"') {
+            Some(AngleUnit::Arcseconds)
+        } else {
+            None
+        }
+    }
+
+    /// Parses a signed angle value with an optional unit suffix. Degrees may be followed by minutes
+    /// and seconds (`12°34'56.7"`), and minutes by seconds.
+    fn angle<T: Float>(&mut self, default: AngleUnit) -> Result<Angle<T>, ParseComplexError> {
+        self.skip_whitespace();
+        let negative = self.eat_sign();
+        self.skip_whitespace();
+        let value: T = self.number()?;
+        let angle = match self.angle_unit() {
+            Some(unit @ (AngleUnit::Degrees | AngleUnit::Arcminutes)) => self.sexagesimal(value, unit)?,
+            Some(unit) => Angle::new(value, unit),
+            None => Angle::new(value, default),
+        };
+        Ok(if negative { -angle } else { angle })
+    }
+
+    /// Parses the minutes and seconds that may follow a value in degrees or minutes, returning the
+    /// angle in the smallest unit given.
+    fn sexagesimal<T: Float>(
+        &mut self,
+        mut value: T,
+        mut unit: AngleUnit,
+    ) -> Result<Angle<T>, ParseComplexError> {
+        while unit != AngleUnit::Arcseconds {
+            let before = self.position;
+            self.skip_whitespace();
+            if !self.at_number() {
+                self.position = before;
+                break;
+            }
+            let start = self.position;
+            let part: T = self.number()?;
+            if !(part >= T::ZERO && part < T::from_f64(60.0)) {
+                return Err(ParseComplexError {
+                    kind: ParseComplexErrorKind::InvalidNumber,
+                    position: start,
+                });
+            }
+            let suffix = self.position;
+            let next = match unit {
+                AngleUnit::Degrees => AngleUnit::Arcminutes,
+                _ => AngleUnit::Arcseconds,
+            };
+            if self.angle_unit() != Some(next) {
+                self.position = suffix;
+                self.skip_whitespace();
+                return Err(self.unexpected());
+            }
+            value = value * T::from_f64(60.0) + part;
+            unit = next;
+        }
+        Ok(Angle::new(value, unit))
+    }
+
+    /// Parses the `e^(jθ)` part of the exponential notation, after the magnitude.
+    fn exponential<T: Float>(&mut self) -> Result<Angle<T>, ParseComplexError> {
+        if !self.eat('^') {
+            return Err(self.unexpected());
+        }
+        self.skip_whitespace();
+        let parenthesised = self.eat('(');
+        self.skip_whitespace();
+        let negative = self.eat_sign();
+        self.skip_whitespace();
+        let leading_unit = self.eat_unit();
+        self.skip_whitespace();
+        let value: T = self.number()?;
+        if !leading_unit {
+            self.skip_whitespace();
+            self.eat('*');
+            self.skip_whitespace();
+            if !self.eat_unit() {
+                return Err(self.unexpected());
+            }
+        }
+        let value = if negative { -value } else { value };
+        let angle = Angle::new(value, self.angle_unit().unwrap_or(AngleUnit::Radians));
+        self.skip_whitespace();
+        if parenthesised && !self.eat(')') {
+            return Err(self.unexpected());
+        }
+        Ok(angle)
+    }
+
+    fn complex<T: Float>(&mut self) -> Result<ComplexNumber<T>, ParseComplexError> {
+        self.skip_whitespace();
+        if self.peek().is_none() {
+            return Err(self.error(ParseComplexErrorKind::Empty));
+        }
+        let first = self.term::<T>()?;
+        self.skip_whitespace();
+        let result = match (first, self.peek()) {
+            (Term::Real(magnitude), Some('∠') | Some('<')) => {
+                self.position += 1;
+                ComplexNumber::from_polar(magnitude, self.angle(AngleUnit::Degrees)?)
+            }
+            (Term::Real(magnitude), Some('*') | Some('e')) => {
+                if self.eat('*') {
+                    self.skip_whitespace();
+                }
+                if !self.eat('e') {
+                    return Err(self.unexpected());
+                }
+                ComplexNumber::from_polar(magnitude, self.exponential()?)
+            }
+            (first, Some('+') | Some('-')) => {
+                let operator = self.position;
+                let second = self.term::<T>()?;
+                self.skip_whitespace();
+                if self.peek().is_some() {
+                    return Err(self.unexpected());
+                }
+                match (first, second) {
+                    (Term::Real(real), Term::Imaginary(imaginary))
+                    | (Term::Imaginary(imaginary), Term::Real(real)) => {
+                        ComplexNumber::from_cartesian(real, imaginary)
+                    }
+                    _ => {
+                        return Err(ParseComplexError {
+                            kind: ParseComplexErrorKind::DuplicateComponent,
+                            position: operator,
+                        })
+                    }
+                }
+            }
+            (Term::Real(real), _) => ComplexNumber::from_cartesian(real, T::ZERO),
+            (Term::Imaginary(imaginary), _) => ComplexNumber::from_cartesian(T::ZERO, imaginary),
+        };
+        self.skip_whitespace();
+        if self.peek().is_some() {
+            return Err(self.unexpected());
+        }
+        Ok(result)
+    }
+
+    fn whole_angle<T: Float>(&mut self) -> Result<Angle<T>, ParseComplexError> {
+        self.skip_whitespace();
+        if self.peek().is_none() {
+            return Err(self.error(ParseComplexErrorKind::Empty));
+        }
+        let angle = self.angle(AngleUnit::Degrees)?;
+        self.skip_whitespace();
+        if self.peek().is_some() {
+            return Err(self.unexpected());
+        }
+        Ok(angle)
+    }
+}
+
+impl<T: Float> FromStr for ComplexNumber<T> {
+    type Err = ParseComplexError;
+
+    /// Parses a complex number in Cartesian, polar or exponential notation (see the `parse` module).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let a: ComplexNumber = "-2.5e3j".parse().unwrap();
+    /// assert_eq!(a, ComplexNumber::from_cartesian(0.0, -2500.0));
+    ///
+    /// let b: ComplexNumber = "5<0.52rad".parse().unwrap();
+    /// assert_eq!(b, ComplexNumber::from_polar(5.0, Angle::from_radians(0.52)));
+    ///
+    /// let c: ComplexNumber = "5e^(j0.927)".parse().unwrap();
+    /// assert_eq!(c, ComplexNumber::from_polar(5.0, Angle::from_radians(0.927)));
+    ///
+    /// assert!("5*".parse::<ComplexNumber>().is_err());
+    /// assert_eq!("5*+3j".parse::<ComplexNumber>().unwrap_err().position(), 2);
+    ///
+    /// let d: Complex32 = "5∠30°".parse().unwrap();
+    /// assert_eq!(d, ComplexNumber::from_polar(5.0_f32, Angle::from_degrees(30.0)));
+    ///
+    /// assert_eq!("5∠1°75'".parse::<ComplexNumber>().unwrap_err().position(), 4);
+    /// ```
+    fn from_str(s: &str) -> Result<ComplexNumber<T>, ParseComplexError> {
+        let mut parser = Parser { chars: s.chars().collect(), position: 0 };
+        parser.complex()
+    }
+}
+
+impl<T: Float> FromStr for Angle<T> {
+    type Err = ParseComplexError;
+
+    /// Parses a signed angle with an optional unit suffix: `°` or `deg`, `rad` or `㎭`, `mrad`,
+    /// `grad` or `gon`, `turn` or `rev`, `'`, `′` or `arcmin`, and `"`, `″` or `arcsec`. Without a
+    /// unit the angle is in degrees. Degrees and minutes may be followed by minutes and seconds, as in
+    /// `12°34'56.7"`, giving an angle stored in the smallest unit written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, angle::AngleUnit};
+    ///
+    /// let a: Angle = "12°34'56.7\"".parse().unwrap();
+    /// assert_eq!(a.unit(), AngleUnit::Arcseconds);
+    /// assert_eq!(format!("{:.1}", a.to_dms()), "12°34'56.7\"");
+    ///
+    /// let b: Angle = "-0.25 turn".parse().unwrap();
+    /// assert_eq!(b.degrees(), -90.0);
+    ///
+    /// let c: Angle = "100grad".parse().unwrap();
+    /// assert_eq!(c.in_unit(AngleUnit::Turns), 0.25);
+    ///
+    /// assert_eq!("12°34".parse::<Angle>().unwrap_err().position(), 5);
+    /// let err = "12°60'".parse::<Angle>().unwrap_err();
+    /// assert_eq!(err.kind(), parse::ParseComplexErrorKind::InvalidNumber);
+    /// assert_eq!(err.position(), 3);
+    ///
+    /// assert_eq!(" ".parse::<Angle>().unwrap_err().to_string(), "cannot parse an empty string");
+    /// ```
+    fn from_str(s: &str) -> Result<Angle<T>, ParseComplexError> {
+        let mut parser = Parser { chars: s.chars().collect(), position: 0 };
+        parser.whole_angle()
+    }
+}
@@ -0,0 +1,356 @@
+//! Renderers that write complex numbers and angles as LaTeX, MathML and compact Unicode for use in
+//! reports. They take the same `ComplexFormat` as `ComplexNumber::format_with`, so the form, the
+//! imaginary unit, the separator style, the precision and scientific notation carry over unchanged.
+//! Scientific notation is written as a power of ten in every markup (`1.5 \times 10^{-9}`, `1.5×10⁻⁹`).
+
+use super::format::{split_sign, ComplexFormat, Form, Separator};
+use super::*;
+
+/// The markup languages the renderers can write.
+#[derive(Clone, Copy)]
+enum Markup {
+    Latex,
+    MathMl,
+    Unicode,
+}
+
+impl<T: Float> ComplexNumber<T> {
+    /// Renders this complex number as LaTeX math-mode source.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The `ComplexFormat` that selects the form, unit and precision.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, format::*};
+    ///
+    /// let z = ComplexNumber::from_cartesian(3.0, 4.0);
+    /// assert_eq!(z.to_latex(ComplexFormat::new()), r"3 + 4\mathrm{j}");
+    ///
+    /// let polar = ComplexFormat::new().form(Form::PolarDegrees).precision(2);
+    /// assert_eq!(z.to_latex(polar), r"5.00\angle 53.13^\circ");
+    ///
+    /// let exponential = ComplexFormat::new().form(Form::Exponential).precision(3);
+    /// let below = ComplexNumber::from_cartesian(3.0, -4.0);
+    /// assert_eq!(below.to_latex(exponential), r"5.000e^{-j0.927}");
+    /// ```
+    pub fn to_latex(&self, format: ComplexFormat) -> String {
+        render_complex(self, &format, Markup::Latex)
+    }
+
+    /// Renders this complex number as a MathML `<math>` element.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The `ComplexFormat` that selects the form, unit and precision.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, format::*};
+    ///
+    /// let z = ComplexNumber::from_cartesian(3.0, -4.0);
+    /// assert_eq!(
+    ///     z.to_mathml(ComplexFormat::new()),
+    ///     "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+    ///      <mn>3</mn><mo>−</mo><mn>4</mn><mi mathvariant=\"normal\">j</mi></math>"
+    /// );
+    /// ```
+    pub fn to_mathml(&self, format: ComplexFormat) -> String {
+        math_element(render_complex(self, &format, Markup::MathMl))
+    }
+
+    /// Renders this complex number as plain Unicode text, with a true minus sign and with exponents
+    /// written as superscripts.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The `ComplexFormat` that selects the form, unit and precision.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, format::*};
+    ///
+    /// let z = ComplexNumber::from_cartesian(3.0, -4.0);
+    /// assert_eq!(z.to_unicode(ComplexFormat::new()), "3 − 4j");
+    ///
+    /// let exponential = ComplexFormat::new().form(Form::Exponential).precision(3);
+    /// assert_eq!(z.to_unicode(exponential), "5.000e⁻ʲ⁰·⁹²⁷");
+    ///
+    /// let scientific = ComplexFormat::new().scientific(true).separator(Separator::Compact);
+    /// let small = ComplexNumber::from_cartesian(1.5e-9, 2e3);
+    /// assert_eq!(small.to_unicode(scientific), "1.5×10⁻⁹+2×10³j");
+    /// ```
+    pub fn to_unicode(&self, format: ComplexFormat) -> String {
+        render_complex(self, &format, Markup::Unicode)
+    }
+}
+
+impl<T: Float> Angle<T> {
+    /// Renders this angle as LaTeX math-mode source, in radians if the format's form is
+    /// `PolarRadians` or `Exponential` and in degrees otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The `ComplexFormat` that selects the unit and precision.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, format::*};
+    ///
+    /// let angle = Angle::from_degrees(30.0);
+    /// assert_eq!(angle.to_latex(ComplexFormat::new()), r"30^\circ");
+    ///
+    /// let radians = ComplexFormat::new().form(Form::PolarRadians).precision(4);
+    /// assert_eq!(angle.to_latex(radians), r"0.5236\,\mathrm{rad}");
+    /// ```
+    pub fn to_latex(&self, format: ComplexFormat) -> String {
+        render_angle(self, &format, Markup::Latex, true)
+    }
+
+    /// Renders this angle as a MathML `<math>` element, in radians if the format's form is
+    /// `PolarRadians` or `Exponential` and in degrees otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The `ComplexFormat` that selects the unit and precision.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, format::*};
+    ///
+    /// let angle = Angle::from_degrees(-45.0);
+    /// assert_eq!(
+    ///     angle.to_mathml(ComplexFormat::new()),
+    ///     "<math xmlns=\"http://www.w3.org/1998/Math/MathML\"><mo>−</mo><mn>45</mn><mo>°</mo></math>"
+    /// );
+    /// ```
+    pub fn to_mathml(&self, format: ComplexFormat) -> String {
+        math_element(render_angle(self, &format, Markup::MathMl, true))
+    }
+
+    /// Renders this angle as plain Unicode text, in radians (`㎭`) if the format's form is
+    /// `PolarRadians` or `Exponential` and in degrees otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The `ComplexFormat` that selects the unit and precision.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{*, format::*};
+    ///
+    /// let angle = Angle::from_degrees(-45.0);
+    /// assert_eq!(angle.to_unicode(ComplexFormat::new()), "−45°");
+    ///
+    /// let radians = ComplexFormat::new().form(Form::PolarRadians).precision(3);
+    /// assert_eq!(angle.to_unicode(radians), "−0.785㎭");
+    /// ```
+    pub fn to_unicode(&self, format: ComplexFormat) -> String {
+        render_angle(self, &format, Markup::Unicode, true)
+    }
+}
+
+fn math_element(body: String) -> String {
+    format!(
+        "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">{}</math>",
+        body
+    )
+}
+
+fn render_complex<T: Float>(
+    z: &ComplexNumber<T>,
+    format: &ComplexFormat,
+    markup: Markup,
+) -> String {
+    let gap = match (format.get_separator(), markup) {
+        (Separator::Spaced, Markup::Latex) | (Separator::Spaced, Markup::Unicode) => " ",
+        _ => "",
+    };
+    let unit = format.get_unit().symbol();
+    match format.get_form() {
+        Form::Cartesian => {
+            let real = number(format.number(z.real(), true), markup);
+            let (operator, imaginary) = split_sign(z.imag());
+            let imaginary = number(format.number(imaginary, false), markup);
+            let operator = operator_symbol(operator, markup);
+            match markup {
+                Markup::Latex => format!(
+                    "{}{}{}{}{}\\mathrm{{{}}}",
+                    real, gap, operator, gap, imaginary, unit
+                ),
+                Markup::MathMl => format!(
+                    "{}{}{}<mi mathvariant=\"normal\">{}</mi>",
+                    real, operator, imaginary, unit
+                ),
+                Markup::Unicode => {
+                    format!("{}{}{}{}{}{}", real, gap, operator, gap, imaginary, unit)
+                }
+            }
+        }
+        Form::PolarRadians | Form::PolarDegrees => {
+            let magnitude = number(format.number(z.real().hypot(z.imag()), true), markup);
+            let angle = render_angle(
+                &Angle::from_radians(z.imag().atan2(z.real())),
+                format,
+                markup,
+                false,
+            );
+            match markup {
+                Markup::Latex => format!("{}\\angle {}", magnitude, angle),
+                Markup::MathMl => format!("{}<mo>∠</mo>{}", magnitude, angle),
+                Markup::Unicode => format!("{}{}∠{}{}", magnitude, gap, gap, angle),
+            }
+        }
+        Form::Exponential => {
+            let magnitude = number(format.number(z.real().hypot(z.imag()), true), markup);
+            let (sign, angle) = split_sign(z.imag().atan2(z.real()));
+            let angle = format.number(angle, false);
+            let sign = if sign == '-' { "-" } else { "" };
+            match markup {
+                Markup::Latex => {
+                    // Keep a control word such as `\infty` from running into the `e`.
+                    let space = if magnitude.ends_with(|c: char| c.is_ascii_alphabetic()) {
+                        " "
+                    } else {
+                        ""
+                    };
+                    format!(
+                        "{}{}e^{{{}{}{}}}",
+                        magnitude,
+                        space,
+                        sign,
+                        unit,
+                        number(angle, markup)
+                    )
+                }
+                Markup::MathMl => format!(
+                    "{}<msup><mi>e</mi><mrow>{}<mi>{}</mi>{}</mrow></msup>",
+                    magnitude,
+                    if sign.is_empty() { "" } else { "<mo>−</mo>" },
+                    unit,
+                    number(angle, markup)
+                ),
+                Markup::Unicode => format!(
+                    "{}e{}",
+                    magnitude,
+                    superscript(&format!("{}{}{}", sign, unit, angle))
+                ),
+            }
+        }
+    }
+}
+
+/// Renders an angle with its unit. `leading` is `true` when the angle stands on its own, so that
+/// the format's `sign_plus` applies to it.
+fn render_angle<T: Float>(
+    angle: &Angle<T>,
+    format: &ComplexFormat,
+    markup: Markup,
+    leading: bool,
+) -> String {
+    match format.get_form() {
+        Form::PolarRadians | Form::Exponential => {
+            let value = number(format.number(angle.radians(), leading), markup);
+            match markup {
+                Markup::Latex => format!("{}\\,\\mathrm{{rad}}", value),
+                Markup::MathMl => format!("{}<mi>rad</mi>", value),
+                Markup::Unicode => format!("{}㎭", value),
+            }
+        }
+        Form::Cartesian | Form::PolarDegrees => {
+            let value = number(format.number(angle.degrees(), leading), markup);
+            match markup {
+                Markup::Latex => format!("{}^\\circ", value),
+                Markup::MathMl => format!("{}<mo>°</mo>", value),
+                Markup::Unicode => format!("{}°", value),
+            }
+        }
+    }
+}
+
+fn operator_symbol(operator: char, markup: Markup) -> String {
+    match (operator, markup) {
+        ('-', Markup::Latex) => String::from("-"),
+        (_, Markup::Latex) => String::from("+"),
+        ('-', Markup::MathMl) => String::from("<mo>−</mo>"),
+        (_, Markup::MathMl) => String::from("<mo>+</mo>"),
+        ('-', Markup::Unicode) => String::from("−"),
+        (_, Markup::Unicode) => String::from("+"),
+    }
+}
+
+/// Converts a number written by `ComplexFormat::number` (such as `-1.5e-9`, `+3.00`, `inf` or `NaN`)
+/// into the given markup.
+fn number(text: String, markup: Markup) -> String {
+    let (sign, rest) = match text.chars().next() {
+        Some(c @ ('-' | '+')) => (Some(c), &text[1..]),
+        _ => (None, &text[..]),
+    };
+    let (mantissa, exponent) = match rest.find('e') {
+        Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+        None => (rest, None),
+    };
+    let mut out = String::new();
+    if let Some(sign) = sign {
+        out.push_str(&operator_symbol(sign, markup));
+    }
+    match (markup, mantissa) {
+        (Markup::Latex, "inf") => out.push_str("\\infty"),
+        (Markup::Latex, "NaN") => out.push_str("\\mathrm{NaN}"),
+        (Markup::MathMl, "inf") => out.push_str("<mi>∞</mi>"),
+        (Markup::MathMl, "NaN") => out.push_str("<mi>NaN</mi>"),
+        (Markup::Unicode, "inf") => out.push('∞'),
+        (Markup::MathMl, _) => out.push_str(&format!("<mn>{}</mn>", mantissa)),
+        _ => out.push_str(mantissa),
+    }
+    if let Some(exponent) = exponent {
+        match markup {
+            Markup::Latex => out.push_str(&format!(" \\times 10^{{{}}}", exponent)),
+            Markup::MathMl => {
+                let exponent = match exponent.strip_prefix('-') {
+                    Some(digits) => format!("<mrow><mo>−</mo><mn>{}</mn></mrow>", digits),
+                    None => format!("<mn>{}</mn>", exponent),
+                };
+                out.push_str(&format!("<mo>×</mo><msup><mn>10</mn>{}</msup>", exponent));
+            }
+            Markup::Unicode => out.push_str(&format!("×10{}", superscript(exponent))),
+        }
+    }
+    out
+}
+
+/// Writes text in Unicode superscript characters, using a raised dot for the decimal point.
+fn superscript(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            '+' => '⁺',
+            '-' => '⁻',
+            '.' => '·',
+            'e' => 'ᵉ',
+            'i' => 'ⁱ',
+            'j' => 'ʲ',
+            'n' => 'ⁿ',
+            'f' => 'ᶠ',
+            'a' => 'ᵃ',
+            'N' => 'ᴺ',
+            other => other,
+        })
+        .collect()
+}
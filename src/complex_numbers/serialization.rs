@@ -0,0 +1,487 @@
+//! # `serialization` Module
+//!
+//! This module is available with the `serde` cargo feature. It implements `Serialize` and `Deserialize`
+//! for `ComplexNumber`, `Angle`, `Radian` and `Degree`.
+//!
+//! A `ComplexNumber` serializes as a `{re, im}` map by default. Two other layouts can be selected per
+//! field, either with `#[serde(with = "...")]` and the `cartesian`, `tuple` and `polar` modules, or by
+//! wrapping the value in `AsTuple` or `AsPolar`:
+//!
+//! * Cartesian - `{"re": 3.0, "im": 4.0}`
+//! * Tuple - `[3.0, 4.0]`
+//! * Polar - `{"mag": 5.0, "deg": 53.13010235415598}`
+//!
+//! Deserialization accepts any of the three layouts, and also `{mag, rad}` or a magnitude with any other
+//! angle unit, whichever was used to write the data. Binary formats such as bincode do not describe
+//! their own layout, so there each value is read back in the layout it was written with; formats that
+//! write structs as maps, such as CBOR, also accept any unit. Angles always carry an explicit unit:
+//! `Angle` serializes in its own unit (`{"rad": value}`, `{"deg": value}`, `{"grad": value}`,
+//! `{"turn": value}`, `{"mrad": value}`, `{"arcmin": value}` or `{"arcsec": value}`), `Radian` as
+//! `{"rad": value}` and `Degree` as `{"deg": value}`, and each of them accepts any unit when read back.
+//!
+//! ```
+//! use complexible::complex_numbers::{*, angle::Degree};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Measurement {
+//!     impedance: ComplexNumber,
+//!     #[serde(with = "complexible::complex_numbers::serialization::tuple")]
+//!     gain: ComplexNumber,
+//!     #[serde(with = "complexible::complex_numbers::serialization::polar")]
+//!     reflection: ComplexNumber,
+//!     phase_margin: Degree,
+//! }
+//!
+//! let measurement = Measurement {
+//!     impedance: ComplexNumber::from_cartesian(50.0, -12.5),
+//!     gain: ComplexNumber::from_cartesian(0.5, 0.25),
+//!     reflection: ComplexNumber::from_cartesian(0.0, 0.5),
+//!     phase_margin: Degree::from(45.0),
+//! };
+//! let json = serde_json::to_string(&measurement).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"impedance":{"re":50.0,"im":-12.5},"gain":[0.5,0.25],"reflection":{"mag":0.5,"deg":90.0},"phase_margin":{"deg":45.0}}"#
+//! );
+//!
+//! let read: Measurement = serde_json::from_str(&json).unwrap();
+//! assert_eq!(read.impedance, measurement.impedance);
+//! assert_eq!(read.gain, measurement.gain);
+//! assert_eq!(read.phase_margin.value, 45.0);
+//! ```
+//!
+//! ```
+//! use complexible::complex_numbers::*;
+//!
+//! let angle = Angle::from_radians(0.5);
+//! assert_eq!(serde_json::to_string(&angle).unwrap(), r#"{"rad":0.5}"#);
+//!
+//! let read: Angle = serde_json::from_str(r#"{"deg":90.0}"#).unwrap();
+//! assert_eq!(read.radians(), std::f64::consts::FRAC_PI_2);
+//!
+//! let turn: Angle = serde_json::from_str(r#"{"turn":0.25}"#).unwrap();
+//! assert_eq!(turn.degrees(), 90.0);
+//! assert_eq!(serde_json::to_string(&turn).unwrap(), r#"{"turn":0.25}"#);
+//!
+//! let mixed = serde_json::from_str::<ComplexNumber>(r#"{"re":1.0,"deg":90.0}"#);
+//! assert!(mixed.is_err());
+//! ```
+//!
+//! ```
+//! use complexible::complex_numbers::{*, serialization::{AsPolar, AsTuple}};
+//!
+//! let z = ComplexNumber::from_cartesian(3.0, -4.0);
+//! let bytes = bincode::serialize(&z).unwrap();
+//! assert_eq!(bincode::deserialize::<ComplexNumber>(&bytes).unwrap(), z);
+//!
+//! let bytes = bincode::serialize(&AsTuple(z)).unwrap();
+//! assert_eq!(bincode::deserialize::<AsTuple>(&bytes).unwrap().0, z);
+//!
+//! let bytes = bincode::serialize(&AsPolar(z)).unwrap();
+//! assert!(bincode::deserialize::<AsPolar>(&bytes).unwrap().0.abs_diff_eq(&z, 1e-15));
+//!
+//! let angle = Angle::new(0.25, angle::AngleUnit::Turns);
+//! let bytes = bincode::serialize(&angle).unwrap();
+//! assert_eq!(bincode::deserialize::<Angle>(&bytes).unwrap(), angle);
+//! ```
+//!
+//! ```
+//! use complexible::complex_numbers::{*, serialization::AsPolar};
+//!
+//! let z = ComplexNumber::from_cartesian(0.0, 2.0);
+//! let mut bytes = Vec::new();
+//! ciborium::into_writer(&AsPolar(z), &mut bytes).unwrap();
+//! let read: AsPolar = ciborium::from_reader(&bytes[..]).unwrap();
+//! assert!(read.0.abs_diff_eq(&z, 1e-15));
+//! ```
+
+use super::*;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, SerializeStruct, SerializeTuple, Serializer};
+use std::marker::PhantomData;
+
+/// A `ComplexNumber` that serializes as a `[re, im]` tuple.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::{*, serialization::AsTuple};
+///
+/// let samples = vec![
+///     AsTuple(ComplexNumber::from_cartesian(1.0, 0.0)),
+///     AsTuple(ComplexNumber::from_cartesian(0.0, -1.0)),
+/// ];
+/// assert_eq!(serde_json::to_string(&samples).unwrap(), "[[1.0,0.0],[0.0,-1.0]]");
+///
+/// let read: Vec<AsTuple> = serde_json::from_str("[[1.0,0.0],[0.0,-1.0]]").unwrap();
+/// assert_eq!(read[1].0.imag(), -1.0);
+///
+/// #[derive(serde::Deserialize)]
+/// struct Sample {
+///     #[serde(with = "complexible::complex_numbers::serialization::tuple")]
+///     value: ComplexNumber,
+/// }
+/// let map: Sample = serde_json::from_str(r#"{"value":{"re":1.0,"im":2.0}}"#).unwrap();
+/// assert_eq!(map.value, ComplexNumber::from_cartesian(1.0, 2.0));
+/// ```
+#[derive(Debug)]
+pub struct AsTuple<T = f64>(pub ComplexNumber<T>);
+
+/// A `ComplexNumber` that serializes in polar form as `{mag, deg}`.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::{*, serialization::AsPolar};
+///
+/// let z = AsPolar(ComplexNumber::from_cartesian(-2.0, 0.0));
+/// assert_eq!(serde_json::to_string(&z).unwrap(), r#"{"mag":2.0,"deg":180.0}"#);
+///
+/// let read: AsPolar = serde_json::from_str(r#"{"mag":2.0,"rad":0.0}"#).unwrap();
+/// assert_eq!(read.0.real(), 2.0);
+/// ```
+#[derive(Debug)]
+pub struct AsPolar<T = f64>(pub ComplexNumber<T>);
+
+/// Serializes and deserializes a `ComplexNumber` as `{re, im}`, for use with
+/// `#[serde(with = "complexible::complex_numbers::serialization::cartesian")]`.
+pub mod cartesian {
+    use super::*;
+
+    /// Serializes a complex number as `{re, im}`.
+    pub fn serialize<T, S>(z: &ComplexNumber<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Float + Serialize,
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ComplexNumber", 2)?;
+        state.serialize_field("re", &z.real())?;
+        state.serialize_field("im", &z.imag())?;
+        state.end()
+    }
+
+    /// Deserializes a complex number from any of the supported layouts.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<ComplexNumber<T>, D::Error>
+    where
+        T: Float + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("ComplexNumber", &["re", "im"], ComplexVisitor(PhantomData))
+    }
+}
+
+/// Serializes and deserializes a `ComplexNumber` as `[re, im]`, for use with
+/// `#[serde(with = "complexible::complex_numbers::serialization::tuple")]`.
+pub mod tuple {
+    use super::*;
+
+    /// Serializes a complex number as `[re, im]`.
+    pub fn serialize<T, S>(z: &ComplexNumber<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Float + Serialize,
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&z.real())?;
+        state.serialize_element(&z.imag())?;
+        state.end()
+    }
+
+    /// Deserializes a complex number from any of the supported layouts, or from `[re, im]` in a
+    /// binary format.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<ComplexNumber<T>, D::Error>
+    where
+        T: Float + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ComplexVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_tuple(2, ComplexVisitor(PhantomData))
+        }
+    }
+}
+
+/// Serializes and deserializes a `ComplexNumber` as `{mag, deg}`, for use with
+/// `#[serde(with = "complexible::complex_numbers::serialization::polar")]`.
+pub mod polar {
+    use super::*;
+
+    /// Serializes a complex number as `{mag, deg}`.
+    pub fn serialize<T, S>(z: &ComplexNumber<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Float + Serialize,
+        S: Serializer,
+    {
+        let magnitude = z.real().hypot(z.imag());
+        let angle = Angle::from_radians(z.imag().atan2(z.real()));
+        let mut state = serializer.serialize_struct("ComplexNumber", 2)?;
+        state.serialize_field("mag", &magnitude)?;
+        state.serialize_field("deg", &angle.degrees())?;
+        state.end()
+    }
+
+    /// Deserializes a complex number from any of the supported layouts. A binary format that writes
+    /// structs as sequences is read as `(mag, deg)`.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<ComplexNumber<T>, D::Error>
+    where
+        T: Float + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["mag", "deg"];
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_struct("ComplexNumber", FIELDS, ComplexVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_struct("ComplexNumber", FIELDS, PolarVisitor(PhantomData))
+        }
+    }
+}
+
+impl<T: Float + Serialize> Serialize for ComplexNumber<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        cartesian::serialize(self, serializer)
+    }
+}
+impl<'de, T: Float + Deserialize<'de>> Deserialize<'de> for ComplexNumber<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ComplexVisitor(PhantomData))
+        } else {
+            cartesian::deserialize(deserializer)
+        }
+    }
+}
+
+impl<T: Float + Serialize> Serialize for AsTuple<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        tuple::serialize(&self.0, serializer)
+    }
+}
+impl<'de, T: Float + Deserialize<'de>> Deserialize<'de> for AsTuple<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        tuple::deserialize(deserializer).map(AsTuple)
+    }
+}
+
+impl<T: Float + Serialize> Serialize for AsPolar<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        polar::serialize(&self.0, serializer)
+    }
+}
+impl<'de, T: Float + Deserialize<'de>> Deserialize<'de> for AsPolar<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        polar::deserialize(deserializer).map(AsPolar)
+    }
+}
+
+/// The field names accepted in the map layouts of complex numbers and angles.
+enum Field {
+    Re,
+    Im,
+    Mag,
+    Angle(AngleUnit),
+}
+const FIELDS: &[&str] = &["re", "im", "mag", "rad", "deg", "grad", "turn", "mrad", "arcmin", "arcsec"];
+const UNITS: [AngleUnit; 7] = [
+    AngleUnit::Radians,
+    AngleUnit::Degrees,
+    AngleUnit::Gradians,
+    AngleUnit::Turns,
+    AngleUnit::Milliradians,
+    AngleUnit::Arcminutes,
+    AngleUnit::Arcseconds,
+];
+
+/// Returns the map key of an angle unit.
+fn unit_key(unit: AngleUnit) -> &'static str {
+    match unit {
+        AngleUnit::Radians => "rad",
+        AngleUnit::Degrees => "deg",
+        AngleUnit::Gradians => "grad",
+        AngleUnit::Turns => "turn",
+        AngleUnit::Milliradians => "mrad",
+        AngleUnit::Arcminutes => "arcmin",
+        AngleUnit::Arcseconds => "arcsec",
+    }
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+        impl Visitor<'_> for FieldVisitor {
+            type Value = Field;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("one of `re`, `im`, `mag` or an angle unit such as `deg` or `rad`")
+            }
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                match value {
+                    "re" => Ok(Field::Re),
+                    "im" => Ok(Field::Im),
+                    "mag" => Ok(Field::Mag),
+                    _ => match UNITS.into_iter().find(|&unit| unit_key(unit) == value) {
+                        Some(unit) => Ok(Field::Angle(unit)),
+                        None => Err(de::Error::unknown_field(value, FIELDS)),
+                    },
+                }
+            }
+        }
+        // Binary formats have no identifiers; the keys were written as plain strings.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_identifier(FieldVisitor)
+        } else {
+            deserializer.deserialize_str(FieldVisitor)
+        }
+    }
+}
+
+/// Stores a field value, rejecting a second occurrence of the same field.
+fn set_once<T, E: de::Error>(slot: &mut Option<T>, value: T, name: &'static str) -> Result<(), E> {
+    if slot.is_some() {
+        return Err(de::Error::duplicate_field(name));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+struct ComplexVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Float + Deserialize<'de>> Visitor<'de> for ComplexVisitor<T> {
+    type Value = ComplexNumber<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a complex number as `{re, im}`, `[re, im]`, `{mag, deg}` or `{mag, rad}`")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let real = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let imaginary = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(3, &self));
+        }
+        Ok(ComplexNumber::from_cartesian(real, imaginary))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let (mut re, mut im, mut mag, mut angle) = (None, None, None, None);
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Re => set_once(&mut re, map.next_value()?, "re")?,
+                Field::Im => set_once(&mut im, map.next_value()?, "im")?,
+                Field::Mag => set_once(&mut mag, map.next_value()?, "mag")?,
+                Field::Angle(unit) => {
+                    if angle.is_some() {
+                        return Err(de::Error::custom("a complex number has exactly one angle"));
+                    }
+                    angle = Some(Angle::new(map.next_value()?, unit));
+                }
+            }
+        }
+        match (re, im, mag, angle) {
+            (Some(re), Some(im), None, None) => Ok(ComplexNumber::from_cartesian(re, im)),
+            (None, None, Some(mag), Some(angle)) => Ok(ComplexNumber::from_polar(mag, angle)),
+            _ => Err(de::Error::custom(
+                "expected the fields `re` and `im`, or `mag` and one angle such as `deg` or `rad`",
+            )),
+        }
+    }
+}
+
+/// Reads a polar complex number from a binary format: a `(mag, deg)` sequence, or a map as
+/// `ComplexVisitor` reads it.
+struct PolarVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Float + Deserialize<'de>> Visitor<'de> for PolarVisitor<T> {
+    type Value = ComplexNumber<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a complex number as `(mag, deg)` or `{mag, deg}`")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let magnitude = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let degrees = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(3, &self));
+        }
+        Ok(ComplexNumber::from_polar(magnitude, Angle::from_degrees(degrees)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        ComplexVisitor(PhantomData).visit_map(map)
+    }
+}
+
+/// Reads an angle written as a single unit and value, such as `{rad: value}` or `{deg: value}`.
+struct AngleVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Float + Deserialize<'de>> Visitor<'de> for AngleVisitor<T> {
+    type Value = Angle<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an angle as `{unit: value}`, such as `{rad: value}` or `{deg: value}`")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let angle = match map.next_key()? {
+            Some(Field::Angle(unit)) => Angle::new(map.next_value()?, unit),
+            Some(_) => return Err(de::Error::custom("expected an angle unit such as `rad` or `deg`")),
+            None => return Err(de::Error::invalid_length(0, &self)),
+        };
+        if map.next_key::<Field>()?.is_some() {
+            return Err(de::Error::custom("an angle has exactly one unit"));
+        }
+        Ok(angle)
+    }
+}
+
+fn serialize_unit<T, S>(value: T, unit: &'static str, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Float + Serialize,
+    S: Serializer,
+{
+    let mut state = serializer.serialize_map(Some(1))?;
+    state.serialize_entry(unit, &value)?;
+    state.end()
+}
+
+fn deserialize_angle<'de, T, D>(deserializer: D) -> Result<Angle<T>, D::Error>
+where
+    T: Float + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(AngleVisitor(PhantomData))
+}
+
+impl<T: Float + Serialize> Serialize for Angle<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_unit(self.value(), unit_key(self.unit()), serializer)
+    }
+}
+impl<'de, T: Float + Deserialize<'de>> Deserialize<'de> for Angle<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_angle(deserializer)
+    }
+}
+
+impl<T: Float + Serialize> Serialize for Radian<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_unit(self.value, "rad", serializer)
+    }
+}
+impl<'de, T: Float + Deserialize<'de>> Deserialize<'de> for Radian<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_angle(deserializer).map(|angle: Angle<T>| Radian::from(angle.radians()))
+    }
+}
+
+impl<T: Float + Serialize> Serialize for Degree<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_unit(self.value, "deg", serializer)
+    }
+}
+impl<'de, T: Float + Deserialize<'de>> Deserialize<'de> for Degree<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_angle(deserializer).map(|angle: Angle<T>| Degree::from(angle.degrees()))
+    }
+}
@@ -0,0 +1,135 @@
+//! # `summation` Module
+//!
+//! This module provides compensated summation of complex numbers. Adding thousands of values one by one
+//! rounds after every addition, and when the values differ in magnitude, or cancel each other, the
+//! rounding errors can swamp the result. `CompensatedSum` keeps a running correction term for each part,
+//! following the Kahan–Babuška–Neumaier algorithm, so the total is accurate to about one rounding of
+//! the exact sum regardless of the number of terms.
+//!
+//! ```
+//! use complexible::complex_numbers::{*, summation::CompensatedSum};
+//!
+//! let terms = [
+//!     ComplexNumber::from_cartesian(1.0, -1.0),
+//!     ComplexNumber::from_cartesian(1e100, 1e100),
+//!     ComplexNumber::from_cartesian(1.0, -1.0),
+//!     ComplexNumber::from_cartesian(-1e100, -1e100),
+//! ];
+//! let naive: ComplexNumber = terms.iter().sum();
+//! assert_eq!(naive, ComplexNumber::from_cartesian(0.0, 0.0));
+//!
+//! let compensated: CompensatedSum = terms.iter().collect();
+//! assert_eq!(compensated.total(), ComplexNumber::from_cartesian(2.0, -2.0));
+//! assert_eq!(ComplexNumber::sum_compensated(&terms), ComplexNumber::from_cartesian(2.0, -2.0));
+//! ```
+
+use super::*;
+use std::borrow::Borrow;
+
+/// A running compensated sum of complex numbers.
+///
+/// Values are added with `add`, `extend` or `collect`, and `total` returns the corrected sum at any
+/// point, so the accumulator can sit in a loop that processes a stream.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::{*, summation::CompensatedSum};
+///
+/// let mut sum = CompensatedSum::new();
+/// for k in 0..1000 {
+///     sum.add(ComplexNumber::from_polar(0.1, Angle::from_degrees(0.36 * k as f64)));
+/// }
+/// assert!(sum.total().abs() < 1e-14);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CompensatedSum<T = f64> {
+    sum: ComplexNumber<T>,
+    compensation: ComplexNumber<T>,
+}
+impl<T: Float> Default for CompensatedSum<T> {
+    fn default() -> CompensatedSum<T> {
+        CompensatedSum::new()
+    }
+}
+impl<T: Float> CompensatedSum<T> {
+    /// Creates an empty sum, whose total is zero.
+    pub fn new() -> CompensatedSum<T> {
+        CompensatedSum { sum: ComplexNumber::ZERO, compensation: ComplexNumber::ZERO }
+    }
+
+    /// Adds a complex number to the sum.
+    ///
+    /// # Arguments
+    ///
+    /// * `z` - The complex number to add.
+    pub fn add(&mut self, z: ComplexNumber<T>) {
+        let (re, re_error) = neumaier(self.sum.real(), z.real());
+        let (im, im_error) = neumaier(self.sum.imag(), z.imag());
+        self.sum = ComplexNumber::from_cartesian(re, im);
+        self.compensation = ComplexNumber::from_cartesian(
+            self.compensation.real() + re_error,
+            self.compensation.imag() + im_error,
+        );
+    }
+
+    /// Returns the compensated total of the values added so far.
+    pub fn total(&self) -> ComplexNumber<T> {
+        self.sum + self.compensation
+    }
+}
+
+impl<T: Float, Z: Borrow<ComplexNumber<T>>> Extend<Z> for CompensatedSum<T> {
+    fn extend<I: IntoIterator<Item = Z>>(&mut self, iter: I) {
+        for z in iter {
+            self.add(*z.borrow());
+        }
+    }
+}
+
+impl<T: Float, Z: Borrow<ComplexNumber<T>>> FromIterator<Z> for CompensatedSum<T> {
+    fn from_iter<I: IntoIterator<Item = Z>>(iter: I) -> CompensatedSum<T> {
+        let mut sum = CompensatedSum::new();
+        sum.extend(iter);
+        sum
+    }
+}
+
+impl<T: Float> ComplexNumber<T> {
+    /// Adds up complex numbers, by value or by reference, with compensated summation (see the
+    /// `summation` module). The sum of no values is zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The complex numbers to add up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let tiny = ComplexNumber::from_cartesian(1e-16, -1e-16);
+    /// let mut values = vec![ComplexNumber::from_real(1.0_f64)];
+    /// values.extend(std::iter::repeat(tiny).take(10_000));
+    ///
+    /// let total = ComplexNumber::sum_compensated(&values);
+    /// assert!((total.real() - (1.0 + 1e-12)).abs() < 1e-15);
+    ///
+    /// let naive: ComplexNumber = values.iter().sum();
+    /// assert_eq!(naive.real(), 1.0);
+    /// ```
+    pub fn sum_compensated<I>(values: I) -> ComplexNumber<T>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<ComplexNumber<T>>,
+    {
+        values.into_iter().collect::<CompensatedSum<T>>().total()
+    }
+}
+
+/// Adds `x` to `sum`, returning the rounded sum and the rounding error of the addition.
+fn neumaier<T: Float>(sum: T, x: T) -> (T, T) {
+    let t = sum + x;
+    let error = if sum.abs() >= x.abs() { (sum - t) + x } else { (x - t) + sum };
+    (t, error)
+}
